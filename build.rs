@@ -0,0 +1,6 @@
+fn main() {
+    // `do_doc_cfg` is set when building docs for docs.rs, see `Cargo.toml`.
+    // Declared here rather than in a `[lints]` table, which needs a newer cargo than our MSRV.
+    println!("cargo:rustc-check-cfg=cfg(do_doc_cfg)");
+    println!("cargo:rerun-if-changed=build.rs");
+}
@@ -1,6 +1,13 @@
 use proc_macro2::TokenStream;
-use quote::{quote, ToTokens};
-use syn::{parse_macro_input, LitByte, LitByteStr, LitChar, LitStr};
+use quote::{format_ident, quote, ToTokens};
+use syn::spanned::Spanned as _;
+use syn::{
+    bracketed,
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+    Lit, LitByte, LitByteStr, LitChar, LitInt, LitStr, Macro, Token, Type,
+};
 
 #[proc_macro]
 pub fn from_str(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -10,9 +17,33 @@ pub fn from_str(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
         .into()
 }
 
+#[proc_macro]
+pub fn from_words(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let item = parse_macro_input!(item as Option<LitStr>);
+    expand_words(item)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro]
+pub fn push_back(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let item = parse_macro_input!(item as PushBack);
+    expand_push_back(item)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro]
+pub fn len_of(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let item = parse_macro_input!(item as Macro);
+    expand_len_of(item)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
 #[proc_macro]
 pub fn from_bytes(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let item = parse_macro_input!(item as Option<LitByteStr>);
+    let item = parse_macro_input!(item with ByteSource::parse_opt);
     expand_bytes(item)
         .unwrap_or_else(syn::Error::into_compile_error)
         .into()
@@ -50,6 +81,37 @@ fn expand_chars(lit: Option<LitStr>) -> syn::Result<TokenStream> {
     Ok(root.into_token_stream())
 }
 
+enum Words {
+    Cons(Chars, Box<Self>),
+    Nil,
+}
+
+impl ToTokens for Words {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Words::Cons(word, rest) => quote!(::typeslice::types::Group<#word, #rest>),
+            Words::Nil => quote!(::typeslice::types::GroupNil),
+        })
+    }
+}
+
+fn expand_words(lit: Option<LitStr>) -> syn::Result<TokenStream> {
+    let root = match lit {
+        Some(it) => {
+            let value = it.value();
+            let words = value.split_whitespace().collect::<Vec<_>>();
+            words.into_iter().rev().fold(Words::Nil, |acc, word| {
+                let chars = word.chars().rev().fold(Chars::Nil, |acc, el| {
+                    Chars::Cons(LitChar::new(el, it.span()), Box::new(acc))
+                });
+                Words::Cons(chars, Box::new(acc))
+            })
+        }
+        None => Words::Nil,
+    };
+    Ok(root.into_token_stream())
+}
+
 fn expand_utf8(lit: Option<LitStr>) -> syn::Result<TokenStream> {
     let root = match lit {
         Some(it) => it.value().bytes().rev().fold(Bytes::Nil, |acc, el| {
@@ -74,12 +136,192 @@ impl ToTokens for Bytes {
     }
 }
 
-fn expand_bytes(lit: Option<LitByteStr>) -> syn::Result<TokenStream> {
-    let root = match lit {
-        Some(it) => it.value().into_iter().rev().fold(Bytes::Nil, |acc, el| {
+/// The input to [`from_bytes!`], either a bytestring literal or an array of byte-sized literals.
+enum ByteSource {
+    Str(LitByteStr),
+    Array(Punctuated<SignedLit, Token![,]>),
+}
+
+impl ByteSource {
+    fn parse_opt(input: ParseStream) -> syn::Result<Option<Self>> {
+        match input.is_empty() {
+            true => Ok(None),
+            false => input.parse().map(Some),
+        }
+    }
+}
+
+impl Parse for ByteSource {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        match input.peek(syn::token::Bracket) {
+            true => {
+                let content;
+                bracketed!(content in input);
+                Ok(Self::Array(Punctuated::parse_terminated(&content)?))
+            }
+            false => Ok(Self::Str(input.parse()?)),
+        }
+    }
+}
+
+/// An optionally negated literal.
+struct SignedLit {
+    minus: Option<Token![-]>,
+    lit: Lit,
+}
+
+impl Parse for SignedLit {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            minus: input.parse()?,
+            lit: input.parse()?,
+        })
+    }
+}
+
+impl SignedLit {
+    /// Check that this element fits in a [`u8`], producing a diagnostic naming
+    /// the offending value and its position in the array if it doesn't.
+    fn to_u8(&self, position: usize) -> syn::Result<u8> {
+        let out_of_range = |value: &dyn std::fmt::Display| {
+            syn::Error::new(
+                self.lit.span(),
+                format!("element {position} is out of range for `u8`: {value} is not in 0..=255"),
+            )
+        };
+        match (&self.minus, &self.lit) {
+            (None, Lit::Byte(it)) => Ok(it.value()),
+            (None, Lit::Int(it)) if matches!(it.suffix(), "" | "u8") => {
+                let value = it.base10_parse::<u128>()?;
+                u8::try_from(value).map_err(|_| out_of_range(&value))
+            }
+            (Some(_), Lit::Int(it)) if matches!(it.suffix(), "" | "u8") => {
+                match it.base10_parse::<u128>()? {
+                    0 => Ok(0),
+                    value => Err(out_of_range(&format_args!("-{value}"))),
+                }
+            }
+            _ => Err(syn::Error::new(
+                self.lit.span(),
+                format!("element {position} must be a `u8` or byte literal"),
+            )),
+        }
+    }
+}
+
+fn expand_bytes(src: Option<ByteSource>) -> syn::Result<TokenStream> {
+    let root = match src {
+        Some(ByteSource::Str(it)) => it.value().into_iter().rev().fold(Bytes::Nil, |acc, el| {
             Bytes::Cons(LitByte::new(el, it.span()), Box::new(acc))
         }),
+        Some(ByteSource::Array(elems)) => {
+            let mut bytes = Vec::with_capacity(elems.len());
+            for (position, elem) in elems.iter().enumerate() {
+                bytes.push(LitByte::new(elem.to_u8(position)?, elem.lit.span()));
+            }
+            bytes
+                .into_iter()
+                .rev()
+                .fold(Bytes::Nil, |acc, el| Bytes::Cons(el, Box::new(acc)))
+        }
         None => Bytes::Nil,
     };
     Ok(root.into_token_stream())
 }
+
+/// The input to [`push_back!`]: `Type, literal`.
+struct PushBack {
+    ty: Type,
+    elem: SignedLit,
+}
+
+impl Parse for PushBack {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ty = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let elem = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(Self { ty, elem })
+    }
+}
+
+fn expand_push_back(PushBack { ty, elem }: PushBack) -> syn::Result<TokenStream> {
+    let name = match &elem.lit {
+        Lit::Int(it) => match it.suffix() {
+            "usize" => "Usize",
+            "u8" => "U8",
+            "u16" => "U16",
+            "u32" => "U32",
+            "u64" => "U64",
+            "u128" => "U128",
+            "isize" => "Isize",
+            "i8" => "I8",
+            "i16" => "I16",
+            "i32" => "I32",
+            "i64" => "I64",
+            "i128" => "I128",
+            "" => {
+                return Err(syn::Error::new(
+                    it.span(),
+                    "integer literals must have a suffix to select the element type, e.g `4u8`",
+                ))
+            }
+            _ => return Err(syn::Error::new(it.span(), "unsupported suffix")),
+        },
+        Lit::Byte(_) => "U8",
+        Lit::Char(_) => "Char",
+        Lit::Bool(_) => "Bool",
+        other => {
+            return Err(syn::Error::new(
+                other.span(),
+                "expected an integer, byte, char or bool literal",
+            ))
+        }
+    };
+    if elem.minus.is_some() && !name.starts_with('I') {
+        return Err(syn::Error::new(
+            elem.lit.span(),
+            "only signed integer literals may be negated",
+        ));
+    }
+    let cons = format_ident!("{name}");
+    let nil = format_ident!("{name}Nil");
+    let SignedLit { minus, lit } = elem;
+    Ok(quote! {
+        <#ty as ::typeslice::ops::Concat<
+            ::typeslice::types::#cons<{ #minus #lit }, ::typeslice::types::#nil>
+        >>::Output
+    })
+}
+
+fn expand_len_of(mac: Macro) -> syn::Result<TokenStream> {
+    let Some(name) = mac.path.segments.last().map(|it| it.ident.to_string()) else {
+        return Err(syn::Error::new_spanned(&mac.path, "expected a macro path"));
+    };
+    let len = match &*name {
+        "usize" | "u8" | "u16" | "u32" | "u64" | "u128" | "isize" | "i8" | "i16" | "i32"
+        | "i64" | "i128" | "char" | "bool" => mac
+            .parse_body_with(Punctuated::<SignedLit, Token![,]>::parse_terminated)?
+            .len(),
+        "from_str" => mac
+            .parse_body::<Option<LitStr>>()?
+            .map(|it| it.value().chars().count())
+            .unwrap_or_default(),
+        "utf8" => mac
+            .parse_body::<Option<LitStr>>()?
+            .map(|it| it.value().len())
+            .unwrap_or_default(),
+        "from_bytes" => match mac.parse_body_with(ByteSource::parse_opt)? {
+            Some(ByteSource::Str(it)) => it.value().len(),
+            Some(ByteSource::Array(it)) => it.len(),
+            None => 0,
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &mac.path,
+                "expected one of this crate's element macros, like `u8!` or `from_str!`",
+            ))
+        }
+    };
+    Ok(LitInt::new(&format!("{len}usize"), mac.path.span()).into_token_stream())
+}
@@ -0,0 +1,79 @@
+//! `const` ASCII helpers for [`List`]s of [`prim@u8`]s and [`prim@char`]s.
+//!
+//! The helpers are inherent methods on [`List`], mirroring those on [`prim@u8`] and [`prim@char`].
+//! ```
+//! # use typeslice::TypeSlice as _;
+//! type Method = typeslice::from_bytes!(b"get");
+//! const UPPER: [u8; 3] = Method::LIST.to_ascii_uppercase();
+//! assert_eq!(&UPPER, b"GET");
+//! assert!(Method::LIST.is_ascii_lowercase());
+//! ```
+
+use crate::List;
+
+macro_rules! impl_ascii {
+    ($ty:ty) => {
+        impl<'a> List<'a, $ty> {
+            /// Copy the items into an array, converting ASCII letters to uppercase.
+            ///
+            /// # Panics
+            /// - If `N` is not the length of the list.
+            pub const fn to_ascii_uppercase<const N: usize>(&self) -> [$ty; N] {
+                let mut array = self.to_array::<N>();
+                let mut ix = 0;
+                while ix < N {
+                    array[ix] = array[ix].to_ascii_uppercase();
+                    ix += 1;
+                }
+                array
+            }
+            /// Copy the items into an array, converting ASCII letters to lowercase.
+            ///
+            /// # Panics
+            /// - If `N` is not the length of the list.
+            pub const fn to_ascii_lowercase<const N: usize>(&self) -> [$ty; N] {
+                let mut array = self.to_array::<N>();
+                let mut ix = 0;
+                while ix < N {
+                    array[ix] = array[ix].to_ascii_lowercase();
+                    ix += 1;
+                }
+                array
+            }
+        }
+        impl_ascii!(@predicates $ty:
+            is_ascii_alphabetic,
+            is_ascii_alphanumeric,
+            is_ascii_digit,
+            is_ascii_hexdigit,
+            is_ascii_uppercase,
+            is_ascii_lowercase,
+            is_ascii_punctuation,
+            is_ascii_graphic,
+            is_ascii_whitespace,
+            is_ascii_control,
+        );
+    };
+    (@predicates $ty:ty: $($predicate:ident),* $(,)?) => {
+        impl<'a> List<'a, $ty> {
+            $(
+                #[doc = concat!("Returns true if every item is [`", stringify!($ty), "::", stringify!($predicate), "`].")]
+                ///
+                /// Empty lists return true.
+                pub const fn $predicate(&self) -> bool {
+                    let mut us = *self;
+                    while let Some((it, rest)) = us.split_first() {
+                        if !it.$predicate() {
+                            return false;
+                        }
+                        us = rest;
+                    }
+                    true
+                }
+            )*
+        }
+    };
+}
+
+impl_ascii!(u8);
+impl_ascii!(char);
@@ -48,8 +48,52 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(do_doc_cfg, feature(doc_cfg))]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+/// The unsigned integer subset of [`for_all_const_types`].
+#[rustfmt::skip]
+macro_rules! for_all_unsigned_types {
+    ($do:ident) => {
+        $do!(Usize/UsizeNil for usize = 0); $do!(U8/U8Nil for u8 = 0); $do!(U16/U16Nil for u16 = 0); $do!(U32/U32Nil for u32 = 0); $do!(U64/U64Nil for u64 = 0); $do!(U128/U128Nil for u128 = 0);
+    };
+}
+
+/// The signed integer subset of [`for_all_const_types`].
+#[rustfmt::skip]
+macro_rules! for_all_signed_types {
+    ($do:ident) => {
+        $do!(Isize/IsizeNil for isize = 0); $do!(I8/I8Nil for i8 = 0); $do!(I16/I16Nil for i16 = 0); $do!(I32/I32Nil for i32 = 0); $do!(I64/I64Nil for i64 = 0); $do!(I128/I128Nil for i128 = 0);
+    };
+}
+
+/// The integer subset of [`for_all_const_types`].
+macro_rules! for_all_int_types {
+    ($do:ident) => {
+        for_all_unsigned_types!($do);
+        for_all_signed_types!($do);
+    };
+}
+
+/// > The only allowed types of const parameters are u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, char and bool.
+/// - https://github.com/rust-lang/reference/blob/1afcfd9c66c8f8d582e01d109cfc15976171dfe0/src/items/generics.md#const-generics
+///
+/// Each type is paired with a default value, used to pad arrays.
+#[rustfmt::skip]
+macro_rules! for_all_const_types {
+    ($do:ident) => {
+        for_all_int_types!($do);
+        $do!(Char/CharNil for char = '\0');
+        $do!(Bool/BoolNil for bool = false);
+    };
+}
+
+pub mod ascii;
+mod case_fold;
 mod gen;
-mod utf8;
+pub mod ops;
+pub mod utf16;
+pub mod utf8;
 
 /// Define a type-level [`TypeSlice`](crate::TypeSlice) of [`prim@u8`]s using a single bytestring literal.
 /// This can be more ergonomic than specifying each byte individually using the [`macro@u8`] macro.
@@ -58,6 +102,13 @@ mod utf8;
 /// type Binary = typeslice::from_bytes![b"hello"];
 /// assert!(Binary::LIST.slice_eq(b"hello"))
 /// ```
+/// An array of integer or byte literals is also accepted,
+/// with out-of-range elements reported by position.
+/// ```
+/// # use typeslice::TypeSlice as _;
+/// type Binary = typeslice::from_bytes![[0x68, b'i', 255]];
+/// assert!(Binary::LIST.slice_eq(&[b'h', b'i', 255]))
+/// ```
 /// This is only available when the `macros` feature of this crate is enabled, and it is enabled by default.
 #[cfg(feature = "macros")]
 #[cfg_attr(do_doc_cfg, doc(cfg(feature = "macros")))]
@@ -88,6 +139,47 @@ pub use typeslice_macros::from_str;
 #[cfg_attr(do_doc_cfg, doc(cfg(feature = "macros")))]
 pub use typeslice_macros::utf8;
 
+/// Define a type-level [`TypeGroup`](crate::TypeGroup) of [`prim@char`] slices, one per whitespace-separated word.
+/// ```
+/// # use typeslice::TypeGroup as _;
+/// type Words = typeslice::from_words!("source destination");
+/// let words = Words::LISTS.into_iter().copied().map(String::from_iter).collect::<Vec<_>>();
+/// assert_eq!(words, ["source", "destination"]);
+/// ```
+/// This is only available when the `macros` feature of this crate is enabled, and it is enabled by default.
+#[cfg(feature = "macros")]
+#[cfg_attr(do_doc_cfg, doc(cfg(feature = "macros")))]
+pub use typeslice_macros::from_words;
+
+/// Count the elements in an invocation of one of this crate's macros, as a `usize` literal.
+///
+/// This can be used where a [`TypeSlice::LEN`](crate::TypeSlice::LEN) is awkward, like array lengths.
+/// ```
+/// let array = [0u8; typeslice::len_of!(typeslice::u8![1, 2, 3])];
+/// assert_eq!(array.len(), 3);
+/// assert_eq!(typeslice::len_of!(typeslice::from_str!("𓀕")), 1);
+/// assert_eq!(typeslice::len_of!(typeslice::utf8!("𓀕")), 4);
+/// ```
+/// This is only available when the `macros` feature of this crate is enabled, and it is enabled by default.
+#[cfg(feature = "macros")]
+#[cfg_attr(do_doc_cfg, doc(cfg(feature = "macros")))]
+pub use typeslice_macros::len_of;
+
+/// Append a literal to an existing [`TypeSlice`](crate::TypeSlice) type, using [`ops::Concat`](crate::ops::Concat).
+///
+/// The type of the literal selects the element type,
+/// so integer literals must have a suffix.
+/// ```
+/// use static_assertions::assert_type_eq_all;
+/// type Existing = typeslice::u8![1, 2, 3];
+/// assert_type_eq_all!(typeslice::push_back!(Existing, 4u8), typeslice::u8![1, 2, 3, 4]);
+/// assert_type_eq_all!(typeslice::push_back!(typeslice::char![], 'a'), typeslice::char!['a']);
+/// ```
+/// This is only available when the `macros` feature of this crate is enabled, and it is enabled by default.
+#[cfg(feature = "macros")]
+#[cfg_attr(do_doc_cfg, doc(cfg(feature = "macros")))]
+pub use typeslice_macros::push_back;
+
 /// A type-level slice of items.
 pub trait TypeSlice<T: 'static> {
     /// A list of the actual items.
@@ -97,14 +189,83 @@ pub trait TypeSlice<T: 'static> {
     const LEN: usize;
 }
 
+/// A [`TypeSlice`] of ordered items, which knows at compile time whether it is sorted.
+///
+/// This allows generic code to check the order without a runtime call to [`List::is_sorted`].
+/// ```
+/// use typeslice::SortedSlice;
+///
+/// const fn assert_sorted<S: SortedSlice<u8>>() {
+///     assert!(S::IS_SORTED, "slice must be sorted");
+/// }
+/// assert_sorted::<typeslice::u8![1, 2, 3]>();
+/// ```
+pub trait SortedSlice<T: 'static>: TypeSlice<T> {
+    /// Whether the items are in non-decreasing order.
+    /// See [`List::is_sorted`].
+    const IS_SORTED: bool;
+}
+
+/// A type-level slice of [`TypeSlice`]s.
+///
+/// See [`types::Group`].
+pub trait TypeGroup<T: 'static> {
+    /// A list of the [`TypeSlice::LIST`] of each member.
+    const LISTS: List<'static, List<'static, T>>;
+    /// The number of slices in this group.
+    const LEN: usize;
+}
+
 /// The bridge between a [`TypeSlice`] and runtime logic,
 /// allowing access to elements defined at the type level.
 ///
 /// Supports iteration and indexing, with adapters for compile time use.
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+///
+/// Lists compare equal to slices and arrays with the same elements:
+/// ```
+/// # use typeslice::TypeSlice as _;
+/// assert_eq!(<typeslice::from_bytes!(b"hello")>::LIST, b"hello");
+/// ```
+#[non_exhaustive]
 pub enum List<'a, T> {
-    Item { head: &'a T, rest: &'a Self },
+    /// A non-empty list.
+    /// See [`List::cons`].
+    Item(Node<'a, T>),
+    /// An empty list.
     Empty,
+    /// A list backed by a contiguous slice, see [`List::from_slice`].
+    ///
+    /// [`List::len`] and [`List::get`] are `O(1)` for this variant.
+    Slice(&'a [T]),
+}
+
+/// The first item of a non-empty [`List`], and the items after it.
+///
+/// Created by [`List::cons`], which also records the length,
+/// so that [`List::len`] is `O(1)`.
+#[derive(Debug)]
+pub struct Node<'a, T> {
+    head: &'a T,
+    rest: &'a List<'a, T>,
+    len: usize,
+}
+
+impl<'a, T> Clone for Node<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<'a, T> Copy for Node<'a, T> {}
+
+impl<'a, T> Node<'a, T> {
+    /// The first item.
+    pub const fn head(&self) -> &'a T {
+        self.head
+    }
+    /// The items after [`Node::head`].
+    pub const fn rest(&self) -> &'a List<'a, T> {
+        self.rest
+    }
 }
 
 impl<'a, T> Clone for List<'a, T> {
@@ -121,53 +282,620 @@ impl<'a, T> Default for List<'a, T> {
     }
 }
 
+impl<'a, T: PartialEq> PartialEq for List<'a, T> {
+    /// Compares elements, regardless of how each list is backed.
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<'a, T: Eq> Eq for List<'a, T> {}
+
+impl<'a, T: PartialOrd> PartialOrd for List<'a, T> {
+    /// Compares lexicographically, like a slice.
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<'a, T: Ord> Ord for List<'a, T> {
+    /// Compares lexicographically, like a slice.
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<'a, T: core::hash::Hash> core::hash::Hash for List<'a, T> {
+    /// Hashes like a slice, so lists can be used to look up slice keys.
+    ///
+    /// A [`List::Slice`] is hashed as its slice.
+    /// Otherwise items aren't contiguous, so each is hashed with [`Hash::hash_slice`](core::hash::Hash::hash_slice),
+    /// which only matches a slice if the hasher treats consecutive writes as one stream,
+    /// as [`std::collections::hash_map::DefaultHasher`] does.
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        if let List::Slice(slice) = self {
+            return slice.hash(state);
+        }
+        state.write_usize(self.len());
+        let mut us = *self;
+        loop {
+            match us {
+                List::Item(node) => {
+                    T::hash_slice(core::slice::from_ref(node.head), state);
+                    us = *node.rest;
+                }
+                List::Slice(slice) => return T::hash_slice(slice, state),
+                List::Empty => return,
+            }
+        }
+    }
+}
+
+impl<'a, T: core::fmt::Debug> core::fmt::Debug for List<'a, T> {
+    /// Formats like a slice, e.g `[1, 2, 3]`.
+    ///
+    /// The alternate flag (`#`) formats the structure of the list instead,
+    /// e.g `Item { head: 1, rest: Empty, len: 1 }`.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match (f.alternate(), self) {
+            (false, _) => f.debug_list().entries(self).finish(),
+            (true, Self::Item(Node { head, rest, len })) => f
+                .debug_struct("Item")
+                .field("head", head)
+                .field("rest", rest)
+                .field("len", len)
+                .finish(),
+            (true, Self::Empty) => f.write_str("Empty"),
+            (true, Self::Slice(slice)) => f.debug_tuple("Slice").field(slice).finish(),
+        }
+    }
+}
+
 impl<'a, T> List<'a, T> {
     /// Create an empty list.
     pub const fn new() -> Self {
         Self::Empty
     }
+    /// Create a list backed by `slice`.
+    /// ```
+    /// # use typeslice::{List, TypeSlice as _};
+    /// const LIST: List<u8> = List::from_slice(b"hello");
+    /// assert_eq!(LIST, <typeslice::from_bytes!(b"hello")>::LIST);
+    /// ```
+    pub const fn from_slice(slice: &'a [T]) -> Self {
+        Self::Slice(slice)
+    }
+    /// Create a list of `head` followed by the items in `rest`.
+    pub const fn cons(head: &'a T, rest: &'a Self) -> Self {
+        Self::Item(Node {
+            head,
+            rest,
+            len: rest.len() + 1,
+        })
+    }
     /// Return the number of elements in the list.
     pub const fn len(&self) -> usize {
         match self {
-            List::Item {
-                head: _,
-                rest: next,
-            } => 1 + next.len(),
+            List::Item(node) => node.len,
             List::Empty => 0,
+            List::Slice(slice) => slice.len(),
         }
     }
     /// Get an item by index.
-    pub const fn get(&self, ix: usize) -> Option<&T> {
-        match (self, ix) {
-            (Self::Empty, _) => None,
-            (Self::Item { head, rest: _ }, 0) => Some(head),
-            (
-                Self::Item {
-                    head: _,
-                    rest: next,
-                },
-                _,
-            ) => match ix.checked_sub(1) {
-                Some(nix) => next.get(nix),
+    pub const fn get(&self, mut ix: usize) -> Option<&T> {
+        if let List::Slice(slice) = self {
+            return match ix < slice.len() {
+                true => Some(&slice[ix]),
+                false => None,
+            };
+        }
+        let mut us = *self;
+        while let Some((head, rest)) = us.split_first() {
+            if ix == 0 {
+                return Some(head);
+            }
+            ix -= 1;
+            us = rest;
+        }
+        None
+    }
+    /// Return the list without its first `n` items.
+    ///
+    /// If `n` exceeds the length, the returned list is empty.
+    pub const fn skip(&self, mut n: usize) -> List<'a, T> {
+        if let List::Slice(slice) = self {
+            return match n < slice.len() {
+                true => List::Slice(slice.split_at(n).1),
+                false => List::Empty,
+            };
+        }
+        let mut us = *self;
+        while n > 0 {
+            match us.split_first() {
+                Some((_, rest)) => us = rest,
+                None => break,
+            }
+            n -= 1;
+        }
+        us
+    }
+    /// Return [`None`] if this list is empty, else the first item and the rest of the list.
+    pub const fn split_first(&self) -> Option<(&'a T, List<'a, T>)> {
+        match self {
+            List::Item(node) => Some((node.head, *node.rest)),
+            List::Empty => None,
+            List::Slice(slice) => match slice.split_first() {
+                Some((head, rest)) => Some((head, List::Slice(rest))),
+                None => None,
+            },
+        }
+    }
+    /// Return [`None`] if this list is empty, else the last item and the items before it.
+    pub const fn split_last(&self) -> Option<(&'a T, Prefix<'a, T>)> {
+        let mut us = *self;
+        let mut len = 0;
+        while let Some((head, rest)) = us.split_first() {
+            if rest.is_empty() {
+                return Some((head, Prefix { list: *self, len }));
+            }
+            us = rest;
+            len += 1;
+        }
+        None
+    }
+    /// Get the first item, or [`None`] if the list is empty.
+    pub const fn first(&self) -> Option<&T> {
+        match self.split_first() {
+            Some((head, _)) => Some(head),
+            None => None,
+        }
+    }
+    /// Get the last item, or [`None`] if the list is empty.
+    pub const fn last(&self) -> Option<&T> {
+        let mut us = *self;
+        let mut last = None;
+        while let Some((head, rest)) = us.split_first() {
+            last = Some(head);
+            us = rest;
+        }
+        last
+    }
+    /// Get an item by index from the end of the list, where `0` is the last item.
+    pub const fn nth_from_end(&self, n: usize) -> Option<&T> {
+        match self.len().checked_sub(1) {
+            Some(last) => match last.checked_sub(n) {
+                Some(ix) => self.get(ix),
                 None => None,
             },
+            None => None,
         }
     }
     /// Returns true if the list has no elements.
     pub const fn is_empty(&self) -> bool {
-        matches!(self, Self::Empty)
+        self.len() == 0
     }
-    /// Return [`None`] if this list is empty, else the next item and its successor.
-    pub const fn into_option(self) -> Option<(&'a T, &'a Self)> {
-        match self {
-            List::Item { head, rest: next } => Some((head, next)),
-            List::Empty => None,
+    /// Copy up to the first `N` items into an array padded with `fill`,
+    /// returning it and how many items were copied.
+    pub(crate) const fn copy_prefix<const N: usize>(&self, fill: T) -> ([T; N], usize)
+    where
+        T: Copy,
+    {
+        let mut buf = [fill; N];
+        let mut len = 0;
+        let mut us = *self;
+        while len < N {
+            match us.split_first() {
+                Some((it, rest)) => {
+                    buf[len] = *it;
+                    us = rest;
+                    len += 1;
+                }
+                None => break,
+            }
+        }
+        (buf, len)
+    }
+    /// Copy the items into an array.
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Magic = typeslice::from_bytes!(b"\x7fELF");
+    /// const MAGIC: [u8; 4] = Magic::LIST.to_array();
+    /// assert_eq!(&MAGIC, b"\x7fELF");
+    /// ```
+    ///
+    /// # Panics
+    /// - If `N` is not the length of the list.
+    pub const fn to_array<const N: usize>(&self) -> [T; N]
+    where
+        T: Copy,
+    {
+        if self.len() != N {
+            panic!("array length does not match list length")
+        }
+        let mut array = match self.first() {
+            Some(first) => [*first; N],
+            None => empty_array(),
+        };
+        let mut us = *self;
+        let mut ix = 0;
+        while let Some((head, rest)) = us.split_first() {
+            array[ix] = *head;
+            us = rest;
+            ix += 1;
+        }
+        array
+    }
+    /// Get several items by index,
+    /// or [`None`] if any index is out of bounds.
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Header = typeslice::u8![0x7F, b'E', b'L', b'F', 2, 1];
+    /// const FIELDS: Option<[&u8; 2]> = Header::LIST.get_many([4, 5]);
+    /// assert_eq!(FIELDS, Some([&2, &1]));
+    /// ```
+    pub const fn get_many<const K: usize>(&self, indices: [usize; K]) -> Option<[&'a T; K]> {
+        let mut items = match self.split_first() {
+            Some((head, _)) => [head; K],
+            None => match K {
+                0 => empty_array(),
+                _ => return None,
+            },
+        };
+        let mut ix = 0;
+        while ix < K {
+            items[ix] = match self.skip(indices[ix]).split_first() {
+                Some((head, _)) => head,
+                None => return None,
+            };
+            ix += 1;
+        }
+        Some(items)
+    }
+    /// Copy the items into the start of `buf`, returning the number of items copied.
+    ///
+    /// If `buf` is shorter than the list, only the first `buf.len()` items are copied.
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Header = typeslice::u8![0xCA, 0xFE];
+    /// let mut frame = [0; 4];
+    /// let len = Header::LIST.copy_to_slice(&mut frame);
+    /// assert_eq!(frame[..len], [0xCA, 0xFE]);
+    /// ```
+    ///
+    /// This is not a `const fn`, because mutable references are not allowed in const contexts on this crate's minimum supported Rust version.
+    pub fn copy_to_slice(&self, buf: &mut [T]) -> usize
+    where
+        T: Copy,
+    {
+        let mut count = 0;
+        for (dst, src) in buf.iter_mut().zip(self) {
+            *dst = *src;
+            count += 1;
         }
+        count
     }
     /// Iterate the elements in the list.
     /// Iterator type is `&T`.
     pub const fn iter(&self) -> Iter<'a, T> {
-        Iter { inner: *self }
+        Iter {
+            inner: *self,
+            len: self.len(),
+        }
+    }
+    /// Iterate the elements in the list, calling `f` on each element as it is yielded.
+    ///
+    /// This is a shorthand for [`Iterator::inspect`].
+    pub fn iter_inspect(&self, mut f: impl FnMut(&T)) -> impl Iterator<Item = &'a T> {
+        self.iter().inspect(move |it| f(it))
+    }
+    /// Returns true if the list and `slice` have equal elements.
+    ///
+    /// For primitive lists, `slice_eq` is also available in `const` contexts.
+    pub fn eq_slice<U>(&self, slice: &[U]) -> bool
+    where
+        T: PartialEq<U>,
+    {
+        self.iter().eq(slice)
+    }
+    /// Call a fallible function on each element in the list, stopping at the first error.
+    ///
+    /// This is a shorthand for [`Iterator::try_for_each`].
+    pub fn try_for_each<E>(&self, f: impl FnMut(&'a T) -> Result<(), E>) -> Result<(), E> {
+        self.iter().try_for_each(f)
+    }
+    /// Fold the elements into an accumulator, passing each element's index alongside it.
+    pub fn fold_indexed<A>(&self, init: A, mut f: impl FnMut(A, usize, &'a T) -> A) -> A {
+        self.iter()
+            .enumerate()
+            .fold(init, |acc, (ix, it)| f(acc, ix, it))
+    }
+    /// Fold the elements into an accumulator with a fallible function, stopping at the first error.
+    ///
+    /// This is a shorthand for [`Iterator::try_fold`].
+    pub fn try_fold<A, E>(&self, init: A, f: impl FnMut(A, &'a T) -> Result<A, E>) -> Result<A, E> {
+        self.iter().try_fold(init, f)
+    }
+    /// Return the element that gives the maximum value from `f`.
+    ///
+    /// If several elements are equally maximum, the last is returned.
+    /// See [`Iterator::max_by_key`].
+    pub fn max_by_key<K: Ord>(&self, mut f: impl FnMut(&T) -> K) -> Option<&'a T> {
+        self.iter().max_by_key(|it| f(it))
+    }
+    /// Return the element that gives the minimum value from `f`.
+    ///
+    /// If several elements are equally minimum, the first is returned.
+    /// See [`Iterator::min_by_key`].
+    pub fn min_by_key<K: Ord>(&self, mut f: impl FnMut(&T) -> K) -> Option<&'a T> {
+        self.iter().min_by_key(|it| f(it))
+    }
+    /// Iterate over each pair of adjacent elements.
+    ///
+    /// Lists with fewer than two elements yield nothing.
+    pub fn pairwise(&self) -> impl Iterator<Item = (&'a T, &'a T)> {
+        let rest = match self.split_first() {
+            Some((_, rest)) => rest,
+            None => List::Empty,
+        };
+        self.iter().zip(rest)
+    }
+    /// Iterate over the items in this list, followed by the items in `other`.
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Hello = typeslice::from_str!("hello, ");
+    /// type World = typeslice::from_str!("world");
+    /// const BOTH: typeslice::Chain<char> = Hello::LIST.chain(World::LIST);
+    /// assert_eq!(BOTH.collect::<String>(), "hello, world");
+    /// ```
+    pub const fn chain(self, other: List<'a, T>) -> Chain<'a, T> {
+        Chain {
+            front: self.iter(),
+            back: other.iter(),
+        }
+    }
+    /// Iterate over the items in this list and `other` in lock-step.
+    ///
+    /// Iteration stops at the end of the shorter list.
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Names = typeslice::from_str!("abc");
+    /// type Ids = typeslice::u8![1, 2, 3];
+    /// let pairs = Names::LIST.zip(Ids::LIST).collect::<Vec<_>>();
+    /// assert_eq!(pairs, [(&'a', &1), (&'b', &2), (&'c', &3)]);
+    /// ```
+    pub fn zip<U>(self, other: List<'a, U>) -> core::iter::Zip<Iter<'a, T>, Iter<'a, U>> {
+        self.iter().zip(other.iter())
+    }
+    /// Display the items in the list, separated by `sep`.
+    ///
+    /// The formatter's width, fill and alignment apply to the output as a whole,
+    /// and its precision applies to each item.
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Numbers = typeslice::u8![1, 2, 3];
+    /// assert_eq!(format!("{}", Numbers::LIST.display_with(", ")), "1, 2, 3");
+    /// assert_eq!(format!("{:>9}", Numbers::LIST.display_with(", ")), "  1, 2, 3");
+    /// ```
+    pub const fn display_with<'s>(&self, sep: &'s str) -> DisplayWith<'a, 's, T> {
+        DisplayWith { list: *self, sep }
+    }
+    /// Iterate over each overlapping window of `size` consecutive elements.
+    ///
+    /// Lists with fewer than `size` elements yield nothing.
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Bytes = typeslice::u8![1, 2, 3, 4];
+    /// let sums = Bytes::LIST
+    ///     .windows(2)
+    ///     .map(|it| it.iter().sum::<u8>())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(sums, [3, 5, 7]);
+    /// ```
+    ///
+    /// # Panics
+    /// - If `size` is zero.
+    pub fn windows(&self, size: usize) -> impl Iterator<Item = Prefix<'a, T>> {
+        assert!(size != 0, "window size must be non-zero");
+        let mut remaining = (self.len() + 1).saturating_sub(size);
+        let mut rest = *self;
+        core::iter::from_fn(move || {
+            remaining = remaining.checked_sub(1)?;
+            let window = Prefix {
+                list: rest,
+                len: size,
+            };
+            rest = rest.skip(1);
+            Some(window)
+        })
+    }
+    /// Iterate over consecutive, non-overlapping chunks of `size` elements.
+    ///
+    /// The last chunk is shorter than `size` if the length is not a multiple of `size`.
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Bytes = typeslice::u8![1, 2, 3, 4, 5];
+    /// let sums = Bytes::LIST
+    ///     .chunks(2)
+    ///     .map(|it| it.iter().sum::<u8>())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(sums, [3, 7, 5]);
+    /// ```
+    ///
+    /// # Panics
+    /// - If `size` is zero.
+    pub fn chunks(&self, size: usize) -> impl Iterator<Item = Prefix<'a, T>> {
+        assert!(size != 0, "chunk size must be non-zero");
+        let mut remaining = self.len();
+        let mut rest = *self;
+        core::iter::from_fn(move || {
+            let len = match remaining {
+                0 => return None,
+                remaining => core::cmp::min(size, remaining),
+            };
+            let chunk = Prefix { list: rest, len };
+            remaining -= len;
+            rest = rest.skip(len);
+            Some(chunk)
+        })
+    }
+    /// Split the list on `delim`, yielding the remainder of the list at the start of each segment.
+    ///
+    /// Each segment runs up to the next `delim`, or the end of the list.
+    /// Consecutive, leading or trailing delimiters yield segments that are empty,
+    /// i.e. that start at a `delim` or at the end of the list.
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Csv = typeslice::from_str!("a,,b");
+    /// let segments = Csv::LIST
+    ///     .split_by(',')
+    ///     .map(|it| it.into_iter().take_while(|c| **c != ',').collect::<String>())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(segments, ["a", "", "b"]);
+    /// ```
+    pub fn split_by(&self, delim: T) -> impl Iterator<Item = List<'a, T>>
+    where
+        T: PartialEq,
+    {
+        let mut next = Some(*self);
+        core::iter::from_fn(move || {
+            let start = next?;
+            let mut rest = start;
+            next = loop {
+                match rest.split_first() {
+                    Some((it, tail)) if *it == delim => break Some(tail),
+                    Some((_, tail)) => rest = tail,
+                    None => break None,
+                }
+            };
+            Some(start)
+        })
+    }
+    /// Split the list on `delim`, yielding an iterator over the items in each segment.
+    ///
+    /// Like [`slice::split`], consecutive, leading or trailing delimiters yield empty segments.
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Path = typeslice::from_str!("a/bc/d");
+    /// let segments = Path::LIST
+    ///     .split(&'/')
+    ///     .map(|it| it.collect::<String>())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(segments, ["a", "bc", "d"]);
+    /// ```
+    pub fn split<'d>(&self, delim: &'d T) -> impl Iterator<Item = Iter<'a, T>> + 'd
+    where
+        T: PartialEq,
+        'a: 'd,
+    {
+        let mut next = Some(*self);
+        core::iter::from_fn(move || {
+            let start = next?;
+            let mut rest = start;
+            let mut len = 0;
+            next = loop {
+                match rest.split_first() {
+                    Some((it, tail)) if it == delim => break Some(tail),
+                    Some((_, tail)) => rest = tail,
+                    None => break None,
+                }
+                len += 1;
+            };
+            Some(Iter { inner: start, len })
+        })
+    }
+    /// Return the starting index of every (possibly overlapping) occurrence of `needle`.
+    ///
+    /// An empty `needle` matches at every index, including [`List::len`].
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(do_doc_cfg, doc(cfg(feature = "alloc")))]
+    pub fn match_indices(&self, needle: &[T]) -> alloc::vec::Vec<usize>
+    where
+        T: PartialEq,
+    {
+        let mut indices = alloc::vec::Vec::new();
+        let mut rest = *self;
+        let mut ix = 0;
+        loop {
+            if rest.iter().take(needle.len()).eq(needle) {
+                indices.push(ix)
+            }
+            match rest.split_first() {
+                Some((_, next)) => rest = next,
+                None => return indices,
+            }
+            ix += 1;
+        }
+    }
+    /// Copy the items into a new [`Vec`](alloc::vec::Vec).
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Numbers = typeslice::u8![1, 2, 3];
+    /// assert_eq!(Numbers::LIST.to_vec(), [1, 2, 3]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(do_doc_cfg, doc(cfg(feature = "alloc")))]
+    pub fn to_vec(&self) -> alloc::vec::Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+    /// Join the items with a separator, like [`slice::join`].
+    ///
+    /// Items that implement [`Display`](core::fmt::Display) may be joined into a [`String`](alloc::string::String) with a `&str`,
+    /// and items that implement [`Clone`] may be joined into a [`Vec`](alloc::vec::Vec) with a `&T`.
+    /// See [`Join`].
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Numbers = typeslice::u8![1, 2, 3];
+    /// assert_eq!(Numbers::LIST.join(", "), "1, 2, 3");
+    /// assert_eq!(Numbers::LIST.join(&0), [1, 0, 2, 0, 3]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(do_doc_cfg, doc(cfg(feature = "alloc")))]
+    pub fn join<Sep>(&self, sep: Sep) -> <Self as Join<Sep>>::Output
+    where
+        Self: Join<Sep>,
+    {
+        Join::join(self, sep)
+    }
+    /// Copy the items into a new boxed slice.
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Numbers = typeslice::u8![1, 2, 3];
+    /// let boxed: Box<[u8]> = Numbers::LIST.to_boxed_slice();
+    /// assert_eq!(*boxed, [1, 2, 3]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(do_doc_cfg, doc(cfg(feature = "alloc")))]
+    pub fn to_boxed_slice(&self) -> alloc::boxed::Box<[T]>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(do_doc_cfg, doc(cfg(feature = "alloc")))]
+impl<T: Clone> From<List<'_, T>> for alloc::vec::Vec<T> {
+    fn from(value: List<'_, T>) -> Self {
+        value.to_vec()
+    }
+}
+
+/// Create an array with no elements, for when there are no values to fill a `[T; N]` with.
+///
+/// # Panics
+/// - If `N` is not zero.
+const fn empty_array<T, const N: usize>() -> [T; N] {
+    if N != 0 {
+        panic!("array is not empty")
+    }
+    // SAFETY: N is zero, so there are no items to initialize.
+    #[allow(clippy::uninit_assumed_init)]
+    unsafe {
+        core::mem::MaybeUninit::<[T; N]>::uninit().assume_init()
     }
 }
 
@@ -181,59 +909,560 @@ impl<'a, T> IntoIterator for List<'a, T> {
     }
 }
 
+impl<'a, T> IntoIterator for &List<'a, T> {
+    type Item = &'a T;
+
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T: PartialEq<U>, U> PartialEq<[U]> for List<'a, T> {
+    fn eq(&self, other: &[U]) -> bool {
+        self.eq_slice(other)
+    }
+}
+
+impl<'a, T: PartialEq<U>, U> PartialEq<&[U]> for List<'a, T> {
+    fn eq(&self, other: &&[U]) -> bool {
+        self.eq_slice(other)
+    }
+}
+
+impl<'a, T: PartialEq<U>, U, const N: usize> PartialEq<[U; N]> for List<'a, T> {
+    fn eq(&self, other: &[U; N]) -> bool {
+        self.eq_slice(other)
+    }
+}
+
+impl<'a, T: PartialEq<U>, U, const N: usize> PartialEq<&[U; N]> for List<'a, T> {
+    fn eq(&self, other: &&[U; N]) -> bool {
+        self.eq_slice(*other)
+    }
+}
+
+impl PartialEq<str> for List<'_, char> {
+    fn eq(&self, other: &str) -> bool {
+        self.iter().copied().eq(other.chars())
+    }
+}
+
+impl PartialEq<&str> for List<'_, char> {
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+impl PartialEq<List<'_, char>> for str {
+    fn eq(&self, other: &List<'_, char>) -> bool {
+        *other == *self
+    }
+}
+
+impl PartialEq<List<'_, char>> for &str {
+    fn eq(&self, other: &List<'_, char>) -> bool {
+        *other == **self
+    }
+}
+
 /// Iterator over the elements in a list.
 /// See [`List::iter`].
 pub struct Iter<'a, T> {
     inner: List<'a, T>,
+    /// The number of items remaining,
+    /// which may be fewer than in `inner` after iterating from the back.
+    len: usize,
+}
+
+impl<'a, T> Iter<'a, T> {
+    /// Return the unconsumed remainder of the list.
+    ///
+    /// A [`List`] can only be shortened from the front,
+    /// so this still includes any items that have been yielded by [`DoubleEndedIterator::next_back`].
+    pub const fn as_list(&self) -> List<'a, T> {
+        self.inner
+    }
+}
+
+impl<'a, T> Clone for Iter<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<'a, T> Copy for Iter<'a, T> {}
+
+impl<'a, T: core::fmt::Debug> core::fmt::Debug for Iter<'a, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Iter")
+            .field(&Prefix {
+                list: self.inner,
+                len: self.len,
+            })
+            .finish()
+    }
 }
 
 impl<'a, T> Iterator for Iter<'a, T> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.inner.into_option() {
+        if self.len == 0 {
+            return None;
+        }
+        match self.inner.split_first() {
             Some((t, next)) => {
-                self.inner = *next;
+                self.inner = next;
+                self.len -= 1;
                 Some(t)
             }
             None => None,
         }
     }
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.inner.len(), Some(self.inner.len()))
+        (self.len, Some(self.len))
     }
 }
 
-impl<'a, T> core::ops::Index<usize> for List<'a, T> {
-    type Output = T;
-
-    fn index(&self, index: usize) -> &Self::Output {
-        match self.get(index) {
-            Some(it) => it,
-            None => {
-                panic!(
-                    "index out of bounds: the len is {} but the index is {}",
-                    self.len(),
-                    index
-                )
-            }
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    /// Note that this is `O(n)` in the number of items remaining.
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.len = self.len.checked_sub(1)?;
+        match self.inner.skip(self.len).split_first() {
+            Some((t, _)) => Some(t),
+            None => None,
         }
     }
 }
 
-macro_rules! next_in_list {
-    ($ident:ident) => {
-        match List::into_option(*$ident) {
-            Some((t, next)) => {
-                $ident = next;
-                Some(*t)
-            }
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T> core::iter::FusedIterator for Iter<'a, T> {}
+
+/// Iterator over the elements in two lists.
+/// See [`List::chain`].
+pub struct Chain<'a, T> {
+    front: Iter<'a, T>,
+    back: Iter<'a, T>,
+}
+
+impl<'a, T> Clone for Chain<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<'a, T> Copy for Chain<'a, T> {}
+
+impl<'a, T: core::fmt::Debug> core::fmt::Debug for Chain<'a, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Chain")
+            .field("front", &self.front)
+            .field("back", &self.back)
+            .finish()
+    }
+}
+
+impl<'a, T> Iterator for Chain<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.front.next().or_else(|| self.back.next())
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.front.len() + self.back.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Chain<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.back.next_back().or_else(|| self.front.next_back())
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Chain<'a, T> {}
+
+impl<'a, T> core::iter::FusedIterator for Chain<'a, T> {}
+
+/// The first [`Prefix::len`] items of a [`List`].
+///
+/// A [`List`] can only be shortened from the front,
+/// so this is returned by operations that remove items from the back.
+/// See [`List::split_last`].
+pub struct Prefix<'a, T> {
+    list: List<'a, T>,
+    len: usize,
+}
+
+impl<'a, T> Clone for Prefix<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<'a, T> Copy for Prefix<'a, T> {}
+
+impl<'a, T: core::fmt::Debug> core::fmt::Debug for Prefix<'a, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(*self).finish()
+    }
+}
+
+impl<'a, T> Prefix<'a, T> {
+    /// Return the number of items in the prefix.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+    /// Returns true if the prefix has no items.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// Get an item by index.
+    pub const fn get(&self, ix: usize) -> Option<&'a T> {
+        match ix < self.len {
+            true => match self.list.skip(ix).split_first() {
+                Some((head, _)) => Some(head),
+                None => None,
+            },
+            false => None,
+        }
+    }
+    /// Return [`None`] if this prefix is empty, else the last item and the items before it.
+    pub const fn split_last(&self) -> Option<(&'a T, Prefix<'a, T>)> {
+        match self.len.checked_sub(1) {
+            Some(len) => match self.get(len) {
+                Some(last) => Some((
+                    last,
+                    Prefix {
+                        list: self.list,
+                        len,
+                    },
+                )),
+                None => None,
+            },
+            None => None,
+        }
+    }
+    /// Iterate the items in the prefix.
+    pub const fn iter(&self) -> Iter<'a, T> {
+        Iter {
+            inner: self.list,
+            len: self.len,
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for Prefix<'a, T> {
+    type Item = &'a T;
+
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> core::ops::Index<usize> for List<'a, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.get(index) {
+            Some(it) => it,
+            None => {
+                panic!(
+                    "index out of bounds: the len is {} but the index is {}",
+                    self.len(),
+                    index
+                )
+            }
+        }
+    }
+}
+
+macro_rules! next_in_list {
+    ($ident:ident) => {
+        match List::split_first(&$ident) {
+            Some((t, next)) => {
+                $ident = next;
+                Some(*t)
+            }
             None => None,
         }
     };
 }
 
+/// Parse a [`List`] of ASCII decimal digits as an integer.
+macro_rules! impl_parse {
+    ($elem:ty: unsigned $($unsigned:ident -> $uty:ty),*; signed $($signed:ident -> $ity:ty),* $(;)?) => {
+        impl<'a> List<'a, $elem> {
+            $(
+                #[doc = concat!("Parse the items as an ASCII decimal [`prim@", stringify!($uty), "`].")]
+                ///
+                /// Returns [`None`] if the list is empty, contains a non-digit, or overflows.
+                pub const fn $unsigned(&self) -> Option<$uty> {
+                    if self.is_empty() {
+                        return None;
+                    }
+                    let mut us = *self;
+                    let mut acc: $uty = 0;
+                    while let Some(it) = next_in_list!(us) {
+                        let digit = match it as u32 {
+                            digit @ 0x30..=0x39 => (digit - 0x30) as $uty,
+                            _ => return None,
+                        };
+                        acc = match acc.checked_mul(10) {
+                            Some(it) => match it.checked_add(digit) {
+                                Some(it) => it,
+                                None => return None,
+                            },
+                            None => return None,
+                        };
+                    }
+                    Some(acc)
+                }
+            )*
+            $(
+                #[doc = concat!("Parse the items as an ASCII decimal [`prim@", stringify!($ity), "`], with an optional leading `+` or `-`.")]
+                ///
+                /// Returns [`None`] if there are no digits, a non-digit, or the result overflows.
+                pub const fn $signed(&self) -> Option<$ity> {
+                    let (negative, digits) = match self.split_first() {
+                        Some((sign, rest)) if *sign as u32 == '-' as u32 => (true, rest),
+                        Some((sign, rest)) if *sign as u32 == '+' as u32 => (false, rest),
+                        _ => (false, *self),
+                    };
+                    if digits.is_empty() {
+                        return None;
+                    }
+                    let mut us = digits;
+                    let mut acc: $ity = 0;
+                    while let Some(it) = next_in_list!(us) {
+                        let digit = match it as u32 {
+                            digit @ 0x30..=0x39 => (digit - 0x30) as $ity,
+                            _ => return None,
+                        };
+                        // accumulate negative numbers downwards, so that MIN doesn't overflow
+                        let next = match acc.checked_mul(10) {
+                            Some(it) => match negative {
+                                true => it.checked_sub(digit),
+                                false => it.checked_add(digit),
+                            },
+                            None => None,
+                        };
+                        acc = match next {
+                            Some(it) => it,
+                            None => return None,
+                        };
+                    }
+                    Some(acc)
+                }
+            )*
+        }
+    };
+}
+
+impl_parse!(char: unsigned parse_u64 -> u64, parse_usize -> usize; signed parse_i64 -> i64, parse_isize -> isize);
+impl_parse!(u8: unsigned parse_u64 -> u64, parse_usize -> usize; signed parse_i64 -> i64, parse_isize -> isize);
+
 impl<'a> List<'a, char> {
+    /// Return the number of bytes needed to encode the characters as UTF-8.
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Message = typeslice::from_str!("I am a 𓀕");
+    /// const LEN: usize = Message::LIST.utf8_len();
+    /// const ENCODED: ([u8; LEN], usize) = Message::LIST.to_utf8_array();
+    /// assert_eq!(ENCODED.0, "I am a 𓀕".as_bytes());
+    /// ```
+    pub const fn utf8_len(&self) -> usize {
+        let mut us = *self;
+        let mut len = 0;
+        while let Some(it) = next_in_list!(us) {
+            len += it.len_utf8();
+        }
+        len
+    }
+    /// Encode the characters as UTF-8 into an array,
+    /// returning the array and the number of bytes used.
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Message = typeslice::from_str!("I am a 𓀕");
+    /// const ENCODED: ([u8; 16], usize) = Message::LIST.to_utf8_array();
+    /// static MESSAGE: &[u8] = ENCODED.0.split_at(ENCODED.1).0;
+    /// assert_eq!(MESSAGE, "I am a 𓀕".as_bytes());
+    /// ```
+    ///
+    /// # Panics
+    /// - If the encoded characters do not fit in `N` bytes.
+    pub const fn to_utf8_array<const N: usize>(&self) -> ([u8; N], usize) {
+        let mut array = [0; N];
+        let mut len = 0;
+        let mut us = *self;
+        while let Some(it) = next_in_list!(us) {
+            let (bytes, width) = utf8::encode_utf8(it);
+            if len + width > N {
+                panic!("encoded characters do not fit in the array")
+            }
+            let mut ix = 0;
+            while ix < width {
+                array[len + ix] = bytes[ix];
+                ix += 1;
+            }
+            len += width;
+        }
+        (array, len)
+    }
+    /// Return the Levenshtein distance between the list and `s`,
+    /// i.e the number of single-character insertions, deletions and substitutions to turn one into the other.
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Command = typeslice::from_str!("commit");
+    /// assert_eq!(Command::LIST.edit_distance("comit"), 1);
+    /// assert_eq!(Command::LIST.edit_distance("commit"), 0);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(do_doc_cfg, doc(cfg(feature = "alloc")))]
+    pub fn edit_distance(&self, s: &str) -> usize {
+        // distances from a prefix of the list to each prefix of `s`
+        let mut row = (0..=s.chars().count()).collect::<alloc::vec::Vec<_>>();
+        for (ix, ours) in self.iter().enumerate() {
+            let mut diagonal = row[0];
+            row[0] = ix + 1;
+            for (jx, theirs) in s.chars().enumerate() {
+                let substitution = diagonal + usize::from(*ours != theirs);
+                diagonal = row[jx + 1];
+                row[jx + 1] = substitution.min(row[jx] + 1).min(diagonal + 1);
+            }
+        }
+        row[row.len() - 1]
+    }
+    /// Returns true if all characters are ASCII.
+    pub const fn is_ascii(&self) -> bool {
+        let mut us = *self;
+        while let Some(it) = next_in_list!(us) {
+            if !it.is_ascii() {
+                return false;
+            }
+        }
+        true
+    }
+    /// Returns true if the list and `slice` are equal, ignoring ASCII case.
+    pub const fn eq_ignore_ascii_case(&self, slice: &[char]) -> bool {
+        let mut us = *self;
+        let mut them = slice;
+        loop {
+            match (next_in_list!(us), them.split_first()) {
+                (Some(ours), Some((theirs, rest))) if ours.eq_ignore_ascii_case(theirs) => {
+                    them = rest
+                }
+                (None, None) => return true,
+                _ => return false,
+            }
+        }
+    }
+    /// Returns true if the list and `other` are equal, ignoring case.
+    ///
+    /// This uses Unicode simple case folding, so characters only match single characters,
+    /// e.g `ß` matches `ẞ` but not `ss`.
+    /// See [`Self::eq_ignore_ascii_case`] to only ignore ASCII case.
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Tag = typeslice::from_str!("Straße");
+    /// const _: () = assert!(Tag::LIST.eq_ignore_case(&['s', 'T', 'R', 'A', 'ẞ', 'E']));
+    /// ```
+    pub const fn eq_ignore_case(&self, other: &[char]) -> bool {
+        let mut us = *self;
+        let mut them = other;
+        loop {
+            match (next_in_list!(us), them.split_first()) {
+                (Some(ours), Some((theirs, rest))) => {
+                    if case_fold::fold(ours) != case_fold::fold(*theirs) {
+                        return false;
+                    }
+                    them = rest;
+                }
+                (None, None) => return true,
+                (None, Some(_)) | (Some(_), None) => return false,
+            }
+        }
+    }
+    /// Return the list without any leading ASCII whitespace.
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Padded = typeslice::from_str!("  \ttoken");
+    /// const _: () = assert!(Padded::LIST.trim_ascii_start().str_eq("token"));
+    /// ```
+    pub const fn trim_ascii_start(&self) -> List<'a, char> {
+        let mut us = *self;
+        while let Some((head, rest)) = us.split_first() {
+            if !head.is_ascii_whitespace() {
+                break;
+            }
+            us = rest;
+        }
+        us
+    }
+    /// Return the list without any leading `c`s.
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Padded = typeslice::from_str!("0042");
+    /// const _: () = assert!(Padded::LIST.skip_while_eq('0').str_eq("42"));
+    /// ```
+    pub const fn skip_while_eq(&self, c: char) -> List<'a, char> {
+        let mut us = *self;
+        while let Some((head, rest)) = us.split_first() {
+            if *head != c {
+                break;
+            }
+            us = rest;
+        }
+        us
+    }
+    /// Split the list on whitespace, yielding an iterator over the characters in each word.
+    ///
+    /// Like [`str::split_whitespace`], words are separated by any amount of Unicode whitespace,
+    /// and there are no empty words.
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Help = typeslice::from_str!(" print  a\tmessage ");
+    /// let words = Help::LIST
+    ///     .split_whitespace()
+    ///     .map(|it| it.collect::<String>())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(words, ["print", "a", "message"]);
+    /// ```
+    pub fn split_whitespace(&self) -> impl Iterator<Item = Iter<'a, char>> {
+        let mut rest = *self;
+        core::iter::from_fn(move || {
+            let start = loop {
+                let (head, tail) = rest.split_first()?;
+                if !head.is_whitespace() {
+                    break rest;
+                }
+                rest = tail;
+            };
+            let mut len = 0;
+            while let Some((head, tail)) = rest.split_first() {
+                if head.is_whitespace() {
+                    break;
+                }
+                rest = tail;
+                len += 1;
+            }
+            Some(Iter { inner: start, len })
+        })
+    }
+    /// Returns true if the first character is `c`.
+    ///
+    /// Always false for an empty list.
+    pub const fn starts_with_char(&self, c: char) -> bool {
+        matches!(self.first(), Some(it) if *it == c)
+    }
+    /// Returns true if the last character is `c`.
+    ///
+    /// Always false for an empty list.
+    pub const fn ends_with_char(&self, c: char) -> bool {
+        matches!(self.last(), Some(it) if *it == c)
+    }
     /// `const` - enabled equality checking that can fail at compile time.
     /// ```rust
     /// # use typeslice::TypeSlice;
@@ -245,7 +1474,7 @@ impl<'a> List<'a, char> {
     pub const fn str_eq(&self, s: &str) -> bool {
         use crate::utf8::Pop;
 
-        let mut us = self;
+        let mut us = *self;
         let mut them = s.as_bytes();
 
         loop {
@@ -259,66 +1488,1332 @@ impl<'a> List<'a, char> {
                     false => return false, // found difference
                 },
                 (None, Pop::Empty) => return true, // reached the end, all good
-                (_, Pop::Invalid | Pop::Truncated) => panic!("invalid utf8-8"),
+                (_, Pop::Invalid | Pop::Truncated) => unreachable!(), // `str`s are valid UTF-8
                 (None, Pop::Ok(_)) | (Some(_), Pop::Empty) => return false, //length mismatch
             }
         }
     }
 }
 
-/// Types that implement [`TypeSlice`] for all primitives that can be const-generics.
-///
-/// These types are all _uninhabited_, and cannot be constructed.
-pub mod types {
-    use crate::{List, TypeSlice};
-    use core::marker::PhantomData;
-
-    /// Marks a type as unconstructable.
-    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-    enum Never {}
+impl<'a> List<'a, u8> {
+    /// Returns true if the bytes are the UTF-8 encoding of `s`.
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Tag = typeslice::from_bytes!(b"tag");
+    /// const _: () = assert!(Tag::LIST.eq_str("tag"));
+    /// ```
+    pub const fn eq_str(&self, s: &str) -> bool {
+        self.slice_eq(s.as_bytes())
+    }
+    /// Decode the bytes as UTF-8, iterating over the [`prim@char`]s.
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Message = typeslice::utf8!("I am a 𓀕");
+    /// assert_eq!(Message::LIST.chars().collect::<String>(), "I am a 𓀕");
+    /// ```
+    pub const fn chars(&self) -> utf8::Chars<'a> {
+        utf8::Chars::new(*self)
+    }
+    /// Decode the bytes as UTF-8, iterating over the [`prim@char`]s,
+    /// and replacing invalid sequences with [`char::REPLACEMENT_CHARACTER`].
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Bytes = typeslice::u8![b'h', b'i', 0xFF, b'!'];
+    /// assert_eq!(Bytes::LIST.chars_lossy().collect::<String>(), "hi\u{FFFD}!");
+    /// ```
+    pub const fn chars_lossy(&self) -> utf8::CharsLossy<'a> {
+        utf8::CharsLossy::new(*self)
+    }
+    /// Copy the bytes into an array with a trailing NUL,
+    /// for use with [`CStr::from_bytes_with_nul`](core::ffi::CStr::from_bytes_with_nul).
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// use core::ffi::CStr;
+    /// type Symbol = typeslice::from_bytes!(b"main");
+    /// const SYMBOL: [u8; 5] = Symbol::LIST.to_nul_terminated();
+    /// assert_eq!(CStr::from_bytes_with_nul(&SYMBOL).unwrap().to_bytes(), b"main");
+    /// ```
+    ///
+    /// # Panics
+    /// - If `N` is not one more than the length of the list.
+    /// - If the list contains a NUL.
+    pub const fn to_nul_terminated<const N: usize>(&self) -> [u8; N] {
+        if self.len() + 1 != N {
+            panic!("array length must be one more than list length")
+        }
+        if self.contains(0) {
+            panic!("list contains an interior NUL")
+        }
+        let mut array = [0; N];
+        let mut us = *self;
+        let mut ix = 0;
+        while let Some(it) = next_in_list!(us) {
+            array[ix] = it;
+            ix += 1;
+        }
+        array
+    }
+    /// Copy the bytes and a trailing NUL into the start of `buf`, and return them as a [`CStr`](core::ffi::CStr).
+    ///
+    /// Returns [`None`] if `buf` is too short, or if the list contains a NUL.
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Symbol = typeslice::from_bytes!(b"main");
+    /// let mut buf = [0; 16];
+    /// let symbol = Symbol::LIST.to_cstr_in(&mut buf).unwrap();
+    /// assert_eq!(symbol.to_bytes(), b"main");
+    /// ```
+    pub fn to_cstr_in<'b>(&self, buf: &'b mut [u8]) -> Option<&'b core::ffi::CStr> {
+        if self.contains(0) {
+            return None;
+        }
+        let buf = buf.get_mut(..self.len() + 1)?;
+        let (nul, bytes) = buf.split_last_mut()?;
+        self.copy_to_slice(bytes);
+        *nul = 0;
+        core::ffi::CStr::from_bytes_with_nul(buf).ok()
+    }
+    /// Returns true if all bytes are ASCII.
+    pub const fn is_ascii(&self) -> bool {
+        let mut us = *self;
+        while let Some(it) = next_in_list!(us) {
+            if !it.is_ascii() {
+                return false;
+            }
+        }
+        true
+    }
+    /// Returns true if the bytes are valid UTF-8.
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Message = typeslice::utf8!("I am a 𓀕");
+    /// const _: () = assert!(Message::LIST.is_utf8());
+    /// ```
+    pub const fn is_utf8(&self) -> bool {
+        self.validate_utf8().is_ok()
+    }
+    /// Count the [`prim@char`]s encoded in the bytes,
+    /// or [`None`] if they are not valid UTF-8.
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Message = typeslice::utf8!("I am a 𓀕");
+    /// const COUNT: Option<usize> = Message::LIST.char_count();
+    /// assert_eq!(COUNT, Some(8));
+    /// ```
+    pub const fn char_count(&self) -> Option<usize> {
+        let mut chars = self.chars();
+        let mut count = 0;
+        loop {
+            match chars.pop() {
+                (utf8::Pop::Ok(_), rest) => {
+                    chars = rest;
+                    count += 1;
+                }
+                (utf8::Pop::Empty, _) => return Some(count),
+                (utf8::Pop::Truncated | utf8::Pop::Invalid, _) => return None,
+            }
+        }
+    }
+    /// Check that the bytes are valid UTF-8,
+    /// returning the index of the first invalid or truncated sequence if not.
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Bytes = typeslice::u8![b'h', b'i', 0xFF];
+    /// assert_eq!(Bytes::LIST.validate_utf8(), Err(2));
+    /// ```
+    pub const fn validate_utf8(&self) -> Result<(), usize> {
+        let mut chars = self.chars();
+        let mut offset = 0;
+        loop {
+            match chars.pop() {
+                (utf8::Pop::Ok(c), rest) => {
+                    chars = rest;
+                    offset += c.len_utf8();
+                }
+                (utf8::Pop::Empty, _) => return Ok(()),
+                (utf8::Pop::Truncated | utf8::Pop::Invalid, _) => return Err(offset),
+            }
+        }
+    }
+    /// Returns true if the list and `slice` are equal, ignoring ASCII case.
+    pub const fn eq_ignore_ascii_case(&self, slice: &[u8]) -> bool {
+        let mut us = *self;
+        let mut them = slice;
+        loop {
+            match (next_in_list!(us), them.split_first()) {
+                (Some(ours), Some((theirs, rest))) if ours.eq_ignore_ascii_case(theirs) => {
+                    them = rest
+                }
+                (None, None) => return true,
+                _ => return false,
+            }
+        }
+    }
+    /// Interpret the bytes as a big-endian integer.
+    ///
+    /// Returns [`None`] if there are more than 8 bytes.
+    /// An empty list is `0`.
+    pub const fn to_u64_be(&self) -> Option<u64> {
+        if self.len() > 8 {
+            return None;
+        }
+        let mut us = *self;
+        let mut acc = 0;
+        while let Some(it) = next_in_list!(us) {
+            acc = acc << 8 | it as u64
+        }
+        Some(acc)
+    }
+    /// Interpret the bytes as a little-endian integer.
+    ///
+    /// Returns [`None`] if there are more than 8 bytes.
+    /// An empty list is `0`.
+    pub const fn to_u64_le(&self) -> Option<u64> {
+        if self.len() > 8 {
+            return None;
+        }
+        let mut us = *self;
+        let mut acc = 0;
+        let mut shift = 0;
+        while let Some(it) = next_in_list!(us) {
+            acc |= (it as u64) << shift;
+            shift += 8;
+        }
+        Some(acc)
+    }
+    /// Interpret the bytes as a big-endian integer.
+    ///
+    /// Returns [`None`] if there are more than 16 bytes.
+    /// An empty list is `0`.
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Magic = typeslice::from_bytes!(b"\x7fELF");
+    /// const _: () = assert!(matches!(Magic::LIST.to_uint_be(), Some(0x7F454C46)));
+    /// ```
+    pub const fn to_uint_be(&self) -> Option<u128> {
+        if self.len() > 16 {
+            return None;
+        }
+        let mut us = *self;
+        let mut acc = 0;
+        while let Some(it) = next_in_list!(us) {
+            acc = acc << 8 | it as u128
+        }
+        Some(acc)
+    }
+    /// Interpret the bytes as a little-endian integer.
+    ///
+    /// Returns [`None`] if there are more than 16 bytes.
+    /// An empty list is `0`.
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Magic = typeslice::from_bytes!(b"\x7fELF");
+    /// const _: () = assert!(matches!(Magic::LIST.to_uint_le(), Some(0x464C457F)));
+    /// ```
+    pub const fn to_uint_le(&self) -> Option<u128> {
+        if self.len() > 16 {
+            return None;
+        }
+        let mut us = *self;
+        let mut acc = 0;
+        let mut shift = 0;
+        while let Some(it) = next_in_list!(us) {
+            acc |= (it as u128) << shift;
+            shift += 8;
+        }
+        Some(acc)
+    }
+    /// Copy the bytes into a new [`String`](alloc::string::String),
+    /// returning an error if they are not valid UTF-8.
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Message = typeslice::utf8!("I am a 𓀕");
+    /// assert_eq!(Message::LIST.to_utf8_string().unwrap(), "I am a 𓀕");
+    /// assert!(<typeslice::u8![0xFF]>::LIST.to_utf8_string().is_err());
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(do_doc_cfg, doc(cfg(feature = "alloc")))]
+    pub fn to_utf8_string(&self) -> Result<alloc::string::String, alloc::string::FromUtf8Error> {
+        alloc::string::String::from_utf8(self.to_vec())
+    }
+}
 
-    /// > The only allowed types of const parameters are u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, char and bool.
-    /// - https://github.com/rust-lang/reference/blob/1afcfd9c66c8f8d582e01d109cfc15976171dfe0/src/items/generics.md#const-generics
-    #[rustfmt::skip]
-    macro_rules! for_all_const_types {
-        ($do:ident) => {
-            $do!(Usize/UsizeNil for usize); $do!(U8/U8Nil for u8); $do!(U16/U16Nil for u16); $do!(U32/U32Nil for u32); $do!(U64/U64Nil for u64); $do!(U128/U128Nil for u128);
-            $do!(Isize/IsizeNil for isize); $do!(I8/I8Nil for i8); $do!(I16/I16Nil for i16); $do!(I32/I32Nil for i32); $do!(I64/I64Nil for i64); $do!(I128/I128Nil for i128);
-            $do!(Char/CharNil for char);
-            $do!(Bool/BoolNil for bool);
-        };
+impl<'a> List<'a, bool> {
+    /// Pack the bits into an integer, with the first item as the most significant bit,
+    /// or [`None`] if there are more than 128 items.
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Flags = typeslice::bool![true, false, true, true];
+    /// const _: () = assert!(matches!(Flags::LIST.to_bits(), Some(0b1011)));
+    /// ```
+    pub const fn to_bits(&self) -> Option<u128> {
+        if self.len() > 128 {
+            return None;
+        }
+        let mut us = *self;
+        let mut acc = 0;
+        while let Some(it) = next_in_list!(us) {
+            acc = acc << 1 | it as u128;
+        }
+        Some(acc)
+    }
+    /// Pack the bits into an integer, with the first item as the least significant bit,
+    /// or [`None`] if there are more than 128 items.
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Flags = typeslice::bool![true, false, true, true];
+    /// const _: () = assert!(matches!(Flags::LIST.to_bits_lsb_first(), Some(0b1101)));
+    /// ```
+    pub const fn to_bits_lsb_first(&self) -> Option<u128> {
+        if self.len() > 128 {
+            return None;
+        }
+        let mut us = *self;
+        let mut acc = 0;
+        let mut shift = 0;
+        while let Some(it) = next_in_list!(us) {
+            acc |= (it as u128) << shift;
+            shift += 1;
+        }
+        Some(acc)
     }
+}
 
-    macro_rules! impl_slice_eq {
-        ($name:ident/$nil:ident for $ty:ty) => {
-            impl List<'_, $ty> {
-                /// `const` - enabled equality checking that can fail at compile time.
-                pub const fn slice_eq(&self, slice: &[$ty]) -> bool {
-                    if self.len() != slice.len() {
-                        return false;
+/// Width-checked versions of [`List::to_bits`].
+macro_rules! impl_to_bits {
+    ($($method:ident -> $ty:ty),* $(,)?) => {
+        impl<'a> List<'a, bool> {
+            $(
+                #[doc = concat!("Pack the bits into a [`prim@", stringify!($ty), "`], with the first item as the most significant bit,")]
+                #[doc = concat!("or [`None`] if there are more than [`", stringify!($ty), "::BITS`] items.")]
+                pub const fn $method(&self) -> Option<$ty> {
+                    if self.len() > <$ty>::BITS as usize {
+                        return None;
                     }
-
-                    let mut ix = slice.len();
-                    while let Some(nix) = ix.checked_sub(1) {
-                        let Some(ours) = self.get(nix) else {
-                            unreachable!()
-                        };
-                        if *ours != slice[nix] {
-                            return false;
-                        }
-                        ix = nix
+                    match self.to_bits() {
+                        Some(it) => Some(it as $ty),
+                        None => None,
                     }
-
-                    true
                 }
-            }
-        };
-    }
+            )*
+        }
+    };
+}
 
-    for_all_const_types!(impl_slice_eq);
+impl_to_bits!(to_u8 -> u8, to_u16 -> u16, to_u32 -> u32, to_u64 -> u64);
+
+impl<'a> List<'a, u16> {
+    /// Decode the code units as UTF-16, iterating over the [`prim@char`]s.
+    /// See [`utf16::Chars`].
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Wide = typeslice::u16![0x0068, 0x0069, 0xD80C, 0xDC15];
+    /// assert_eq!(Wide::LIST.chars().collect::<String>(), "hi𓀕");
+    /// ```
+    pub const fn chars(&self) -> utf16::Chars<'a> {
+        utf16::Chars::new(*self)
+    }
+    /// Returns true if the code units are valid UTF-16,
+    /// i.e there are no unpaired surrogates.
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Wide = typeslice::u16![0x0068, 0xD80C, 0xDC15];
+    /// const _: () = assert!(Wide::LIST.is_utf16());
+    /// ```
+    pub const fn is_utf16(&self) -> bool {
+        let mut chars = self.chars();
+        loop {
+            match chars.pop() {
+                (utf16::Pop::Ok(_), rest) => chars = rest,
+                (utf16::Pop::Empty, _) => return true,
+                (utf16::Pop::Truncated | utf16::Pop::Invalid, _) => return false,
+            }
+        }
+    }
+    /// Returns true if the code units are the UTF-16 encoding of `s`.
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// type Wide = typeslice::u16![0x0068, 0xD80C, 0xDC15];
+    /// const _: () = assert!(Wide::LIST.eq_str("h𓀕"));
+    /// ```
+    pub const fn eq_str(&self, s: &str) -> bool {
+        use crate::utf8::Pop;
+
+        let mut chars = self.chars();
+        let mut them = s.as_bytes();
+        loop {
+            match (chars.pop(), utf8::pop(them)) {
+                ((Pop::Ok(ours), rest), Pop::Ok(theirs)) => match ours == theirs {
+                    true => {
+                        chars = rest;
+                        them = them.split_at(theirs.len_utf8()).1;
+                    }
+                    false => return false,
+                },
+                ((Pop::Empty, _), Pop::Empty) => return true,
+                _ => return false,
+            }
+        }
+    }
+}
+
+/// Collects the characters.
+/// [`List`]s of [`prim@char`]s also implement [`Display`](core::fmt::Display),
+/// so can be converted with `to_string`.
+/// ```
+/// # use typeslice::TypeSlice as _;
+/// type Message = typeslice::from_str!("hello");
+/// assert_eq!(String::from(Message::LIST), "hello");
+/// assert_eq!(Message::LIST.to_string(), "hello");
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(do_doc_cfg, doc(cfg(feature = "alloc")))]
+impl From<List<'_, char>> for alloc::string::String {
+    fn from(value: List<'_, char>) -> Self {
+        value.iter().collect()
+    }
+}
+
+/// Join the items in a list with a separator of type `Sep`.
+/// See [`List::join`].
+#[cfg(feature = "alloc")]
+#[cfg_attr(do_doc_cfg, doc(cfg(feature = "alloc")))]
+pub trait Join<Sep> {
+    /// The joined items.
+    type Output;
+    /// Join the items, placing `sep` between each adjacent pair.
+    fn join(&self, sep: Sep) -> Self::Output;
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(do_doc_cfg, doc(cfg(feature = "alloc")))]
+impl<T: core::fmt::Display> Join<&str> for List<'_, T> {
+    type Output = alloc::string::String;
+
+    fn join(&self, sep: &str) -> Self::Output {
+        use alloc::string::ToString as _;
+        self.display_with(sep).to_string()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(do_doc_cfg, doc(cfg(feature = "alloc")))]
+impl<T: Clone> Join<&T> for List<'_, T> {
+    type Output = alloc::vec::Vec<T>;
+
+    fn join(&self, sep: &T) -> Self::Output {
+        let mut joined = alloc::vec::Vec::new();
+        for (ix, it) in self.iter().enumerate() {
+            if ix != 0 {
+                joined.push(sep.clone());
+            }
+            joined.push(it.clone());
+        }
+        joined
+    }
+}
+
+/// A list that can be built at runtime, and viewed as a [`List`].
+///
+/// Compares, orders and hashes like a slice, so like a [`List`].
+/// ```
+/// # use typeslice::{OwnedList, TypeSlice as _};
+/// type Message = typeslice::from_str!("hello");
+/// let owned = "hello".chars().collect::<OwnedList<_>>();
+/// assert_eq!(owned, Message::LIST);
+/// assert!(owned.as_list().str_eq("hello"));
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(do_doc_cfg, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct OwnedList<T> {
+    items: alloc::vec::Vec<T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> OwnedList<T> {
+    /// Create an empty list.
+    pub const fn new() -> Self {
+        Self {
+            items: alloc::vec::Vec::new(),
+        }
+    }
+    /// Return the number of items in the list.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+    /// Returns true if the list has no items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+    /// Get an item by index.
+    pub fn get(&self, ix: usize) -> Option<&T> {
+        self.items.get(ix)
+    }
+    /// Iterate the items in the list.
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.items.iter()
+    }
+    /// View the items as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        &self.items
+    }
+    /// Append an item to the end of the list.
+    pub fn push(&mut self, item: T) {
+        self.items.push(item)
+    }
+    /// View the items as a [`List`],
+    /// so that runtime data may share algorithms with [`TypeSlice`]s.
+    pub fn as_list(&self) -> List<'_, T> {
+        List::from_slice(&self.items)
+    }
+    /// Call `f` with a [`List`] of the items.
+    ///
+    /// This is a shorthand for [`OwnedList::as_list`].
+    pub fn with_list<R>(&self, f: impl for<'a> FnOnce(List<'a, T>) -> R) -> R {
+        f(self.as_list())
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(do_doc_cfg, doc(cfg(feature = "alloc")))]
+impl<T> FromIterator<T> for OwnedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            items: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(do_doc_cfg, doc(cfg(feature = "alloc")))]
+impl<T> From<alloc::vec::Vec<T>> for OwnedList<T> {
+    fn from(items: alloc::vec::Vec<T>) -> Self {
+        Self { items }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(do_doc_cfg, doc(cfg(feature = "alloc")))]
+impl<T: Clone> From<List<'_, T>> for OwnedList<T> {
+    fn from(value: List<'_, T>) -> Self {
+        value.iter().cloned().collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(do_doc_cfg, doc(cfg(feature = "alloc")))]
+impl<'a, T> IntoIterator for &'a OwnedList<T> {
+    type Item = &'a T;
+
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(do_doc_cfg, doc(cfg(feature = "alloc")))]
+impl<T: PartialEq<U>, U> PartialEq<List<'_, U>> for OwnedList<T> {
+    fn eq(&self, other: &List<'_, U>) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(do_doc_cfg, doc(cfg(feature = "alloc")))]
+impl<T: PartialEq<U>, U> PartialEq<OwnedList<U>> for List<'_, T> {
+    fn eq(&self, other: &OwnedList<U>) -> bool {
+        self.eq_slice(other.as_slice())
+    }
+}
+
+/// Format a [`List`] of [`prim@char`]s as a string, without allocating.
+///
+/// Width, fill, alignment and precision behave as they do for a [`prim@str`].
+/// ```
+/// # use typeslice::{Fmt, TypeSlice as _};
+/// type Message = typeslice::from_str!("hello");
+/// assert_eq!(format!("{}", Fmt(Message::LIST)), "hello");
+/// assert_eq!(format!("{:?}", Fmt(Message::LIST)), "\"hello\"");
+/// assert_eq!(format!("{:>8.4}", Fmt(Message::LIST)), "    hell");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Fmt<'a>(pub List<'a, char>);
+
+impl core::fmt::Display for Fmt<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use core::fmt::Write as _;
+        let len = match f.precision() {
+            Some(precision) if precision < self.0.len() => precision,
+            _ => self.0.len(),
+        };
+        write_padded(f, len, |f| {
+            self.0.iter().take(len).try_for_each(|c| f.write_char(*c))
+        })
+    }
+}
+
+/// Call `write` to write `len` [`prim@char`]s,
+/// padding them to the formatter's width like a [`prim@str`].
+fn write_padded(
+    f: &mut core::fmt::Formatter<'_>,
+    len: usize,
+    write: impl FnOnce(&mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+) -> core::fmt::Result {
+    use core::fmt::Write as _;
+
+    let padding = f.width().unwrap_or(0).saturating_sub(len);
+    let (before, after) = match f.align() {
+        Some(core::fmt::Alignment::Right) => (padding, 0),
+        Some(core::fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(core::fmt::Alignment::Left) | None => (0, padding),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    write(f)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+impl core::fmt::Debug for Fmt<'_> {
+    /// Formats like a [`prim@str`], with quotes and escapes.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use core::fmt::Write as _;
+        f.write_char('"')?;
+        self.0
+            .try_for_each(|c| c.escape_debug().try_for_each(|c| f.write_char(c)))?;
+        f.write_char('"')
+    }
+}
+
+/// Writes the characters as a string, like [`Fmt`].
+/// ```
+/// # use typeslice::TypeSlice as _;
+/// type Message = typeslice::from_str!("hello");
+/// assert_eq!(format!("{}", Message::LIST), "hello");
+/// assert_eq!(format!("{:-^9}", Message::LIST), "--hello--");
+/// ```
+impl core::fmt::Display for List<'_, char> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&Fmt(*self), f)
+    }
+}
+
+/// Display a [`List`] with separators.
+/// See [`List::display_with`].
+pub struct DisplayWith<'a, 's, T> {
+    list: List<'a, T>,
+    sep: &'s str,
+}
+
+impl<T> Clone for DisplayWith<'_, '_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T> Copy for DisplayWith<'_, '_, T> {}
+
+impl<T: core::fmt::Display> DisplayWith<'_, '_, T> {
+    fn write_items(
+        &self,
+        w: &mut dyn core::fmt::Write,
+        precision: Option<usize>,
+    ) -> core::fmt::Result {
+        self.list.fold_indexed(Ok(()), |acc, ix, it| {
+            acc?;
+            if ix != 0 {
+                w.write_str(self.sep)?;
+            }
+            match precision {
+                Some(precision) => write!(w, "{:.*}", precision, it),
+                None => write!(w, "{}", it),
+            }
+        })
+    }
+}
+
+impl<T: core::fmt::Display> core::fmt::Display for DisplayWith<'_, '_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        /// Counts the [`prim@char`]s that would be written.
+        struct Count(usize);
+        impl core::fmt::Write for Count {
+            fn write_str(&mut self, s: &str) -> core::fmt::Result {
+                self.0 += s.chars().count();
+                Ok(())
+            }
+        }
+
+        let precision = f.precision();
+        if f.width().is_none() {
+            return self.write_items(f, precision);
+        }
+        let mut count = Count(0);
+        self.write_items(&mut count, precision)?;
+        write_padded(f, count.0, |f| self.write_items(f, precision))
+    }
+}
+
+/// Format a [`List`] of integers in the given radix, separated by spaces.
+/// ```
+/// # use typeslice::{RadixList, TypeSlice as _};
+/// type Bytes = typeslice::u8![255, 16];
+/// let hex = RadixList::new(Bytes::LIST, 16).unwrap();
+/// assert_eq!(hex.to_string(), "ff 10");
+/// let binary = RadixList::new(Bytes::LIST, 2).unwrap();
+/// assert_eq!(binary.to_string(), "11111111 10000");
+/// type Signed = typeslice::i8![-128, 8];
+/// let octal = RadixList::new(Signed::LIST, 8).unwrap();
+/// assert_eq!(octal.to_string(), "-200 10");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RadixList<'a, T> {
+    list: List<'a, T>,
+    radix: u32,
+}
+
+impl<'a, T> RadixList<'a, T> {
+    /// Format `list` in `radix`,
+    /// or return [`None`] if the radix is not in the range `2..=36`.
+    pub const fn new(list: List<'a, T>, radix: u32) -> Option<Self> {
+        match radix {
+            2..=36 => Some(Self { list, radix }),
+            _ => None,
+        }
+    }
+    /// The list to format.
+    pub const fn list(&self) -> List<'a, T> {
+        self.list
+    }
+    /// The radix, in the range `2..=36`.
+    pub const fn radix(&self) -> u32 {
+        self.radix
+    }
+    fn fmt_with(
+        &self,
+        f: &mut core::fmt::Formatter<'_>,
+        to_parts: impl Fn(&T) -> (bool, u128),
+    ) -> core::fmt::Result {
+        use core::fmt::Write as _;
+
+        let radix = self.radix as u128;
+        let mut first = true;
+        self.list.try_for_each(|it| {
+            if !first {
+                f.write_char(' ')?;
+            }
+            first = false;
+            let (negative, mut magnitude) = to_parts(it);
+            if negative {
+                f.write_char('-')?;
+            }
+            // enough for u128::MAX in binary
+            let mut digits = ['0'; 128];
+            let mut start = digits.len();
+            loop {
+                start -= 1;
+                digits[start] = match (magnitude % radix) as u8 {
+                    digit @ 0..=9 => (b'0' + digit) as char,
+                    digit => (b'a' + digit - 10) as char,
+                };
+                magnitude /= radix;
+                if magnitude == 0 {
+                    break;
+                }
+            }
+            digits[start..].iter().try_for_each(|c| f.write_char(*c))
+        })
+    }
+}
+
+macro_rules! impl_radix_unsigned {
+    ($name:ident/$nil:ident for $ty:ty = $default:expr) => {
+        impl core::fmt::Display for RadixList<'_, $ty> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                self.fmt_with(f, |it| (false, *it as u128))
+            }
+        }
+    };
+}
+
+macro_rules! impl_radix_signed {
+    ($name:ident/$nil:ident for $ty:ty = $default:expr) => {
+        impl core::fmt::Display for RadixList<'_, $ty> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                self.fmt_with(f, |it| (*it < 0, it.unsigned_abs() as u128))
+            }
+        }
+    };
+}
+
+for_all_unsigned_types!(impl_radix_unsigned);
+for_all_signed_types!(impl_radix_signed);
+
+/// Write each item in `list` with `write`, after `prefix` if the formatter is in alternate mode.
+fn fmt_concat<T>(
+    list: &List<'_, T>,
+    f: &mut core::fmt::Formatter<'_>,
+    prefix: &str,
+    write: impl Fn(&mut core::fmt::Formatter<'_>, &T) -> core::fmt::Result,
+) -> core::fmt::Result {
+    if f.alternate() {
+        f.write_str(prefix)?;
+    }
+    list.try_for_each(|it| write(f, it))
+}
+
+/// Implement a numeric formatting trait for a [`List`] of integers,
+/// concatenating the items, each zero-padded to the width of the type.
+macro_rules! impl_concat_fmt {
+    ($ty:ty: $($trait:ident, $prefix:literal, $bits_per_digit:literal, $spec:literal);* $(;)?) => {
+        $(
+            /// Concatenates the items, each zero-padded to the width of the type.
+            ///
+            /// The alternate flag (`#`) adds a single prefix.
+            impl core::fmt::$trait for List<'_, $ty> {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    const WIDTH: usize =
+                        (<$ty>::BITS as usize + $bits_per_digit - 1) / $bits_per_digit;
+                    fmt_concat(self, f, $prefix, |f, it| write!(f, $spec, it, WIDTH))
+                }
+            }
+        )*
+    };
+    ($name:ident/$nil:ident for $ty:ty = $default:expr) => {
+        impl_concat_fmt!($ty:
+            LowerHex, "0x", 4, "{:01$x}";
+            UpperHex, "0x", 4, "{:01$X}";
+            Binary, "0b", 1, "{:01$b}";
+            Octal, "0o", 3, "{:01$o}";
+        );
+    };
+}
+
+for_all_int_types!(impl_concat_fmt);
+
+/// Types that implement [`TypeSlice`] for all primitives that can be const-generics.
+///
+/// These types are all _uninhabited_, and cannot be constructed.
+pub mod types {
+    use crate::{List, Prefix, SortedSlice, TypeGroup, TypeSlice};
+    use core::marker::PhantomData;
+
+    /// Marks a type as unconstructable.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    enum Never {}
+
+    macro_rules! impl_primitive {
+        ($name:ident/$nil:ident for $ty:ty = $default:expr) => {
+            impl<'a> List<'a, $ty> {
+                /// `const` - enabled equality checking that can fail at compile time.
+                pub const fn slice_eq(&self, slice: &[$ty]) -> bool {
+                    if self.len() != slice.len() {
+                        return false;
+                    }
+
+                    let mut us = *self;
+                    let mut slice = slice;
+                    while let [theirs, rest @ ..] = slice {
+                        match next_in_list!(us) {
+                            Some(ours) if ours == *theirs => slice = rest,
+                            _ => return false,
+                        }
+                    }
+
+                    true
+                }
+                /// Return the length of the longest prefix shared by the list and `slice`.
+                pub const fn longest_common_prefix(&self, slice: &[$ty]) -> usize {
+                    let mut us = *self;
+                    let mut ix = 0;
+                    while ix < slice.len() {
+                        match next_in_list!(us) {
+                            Some(ours) if ours == slice[ix] => ix += 1,
+                            _ => break,
+                        }
+                    }
+                    ix
+                }
+                /// Returns true if `prefix` is a prefix of the list.
+                pub const fn starts_with(&self, prefix: &[$ty]) -> bool {
+                    let mut us = *self;
+                    let mut them = prefix;
+                    while let Some((theirs, rest)) = them.split_first() {
+                        match next_in_list!(us) {
+                            Some(ours) if ours == *theirs => them = rest,
+                            _ => return false,
+                        }
+                    }
+                    true
+                }
+                /// Returns true if `suffix` is a suffix of the list.
+                pub const fn ends_with(&self, suffix: &[$ty]) -> bool {
+                    match self.len().checked_sub(suffix.len()) {
+                        Some(skip) => self.skip(skip).slice_eq(suffix),
+                        None => false,
+                    }
+                }
+                /// Return the rest of the list after `prefix`,
+                /// or [`None`] if the list doesn't start with `prefix`.
+                pub const fn strip_prefix(&self, prefix: &[$ty]) -> Option<List<'a, $ty>> {
+                    match self.starts_with(prefix) {
+                        true => Some(self.skip(prefix.len())),
+                        false => None,
+                    }
+                }
+                /// Return the items of the list before `suffix`,
+                /// or [`None`] if the list doesn't end with `suffix`.
+                pub const fn strip_suffix(&self, suffix: &[$ty]) -> Option<Prefix<'a, $ty>> {
+                    match self.ends_with(suffix) {
+                        true => Some(Prefix {
+                            list: *self,
+                            len: self.len() - suffix.len(),
+                        }),
+                        false => None,
+                    }
+                }
+                /// Returns true if any element is equal to `item`.
+                pub const fn contains(&self, item: $ty) -> bool {
+                    let mut us = *self;
+                    while let Some(it) = next_in_list!(us) {
+                        if it == item {
+                            return true;
+                        }
+                    }
+                    false
+                }
+                /// Return the index of the first element equal to `item`.
+                pub const fn position(&self, item: $ty) -> Option<usize> {
+                    let mut us = *self;
+                    let mut ix = 0;
+                    while let Some(it) = next_in_list!(us) {
+                        if it == item {
+                            return Some(ix);
+                        }
+                        ix += 1;
+                    }
+                    None
+                }
+                /// Return the index of the last element equal to `item`.
+                pub const fn rposition(&self, item: $ty) -> Option<usize> {
+                    let mut us = *self;
+                    let mut ix = 0;
+                    let mut found = None;
+                    while let Some(it) = next_in_list!(us) {
+                        if it == item {
+                            found = Some(ix);
+                        }
+                        ix += 1;
+                    }
+                    found
+                }
+                /// Count the elements equal to `item`.
+                pub const fn count_of(&self, item: $ty) -> usize {
+                    let mut us = *self;
+                    let mut count = 0;
+                    while let Some(it) = next_in_list!(us) {
+                        if it == item {
+                            count += 1
+                        }
+                    }
+                    count
+                }
+                /// `const` - enabled lexicographic comparison, like [`Ord`] for slices.
+                pub const fn slice_cmp(&self, slice: &[$ty]) -> core::cmp::Ordering {
+                    use core::cmp::Ordering;
+
+                    let mut us = *self;
+                    let mut them = slice;
+                    loop {
+                        match (next_in_list!(us), them.split_first()) {
+                            (Some(ours), Some((theirs, rest))) => {
+                                if ours < *theirs {
+                                    return Ordering::Less;
+                                }
+                                if ours > *theirs {
+                                    return Ordering::Greater;
+                                }
+                                them = rest;
+                            }
+                            (Some(_), None) => return Ordering::Greater,
+                            (None, Some(_)) => return Ordering::Less,
+                            (None, None) => return Ordering::Equal,
+                        }
+                    }
+                }
+                /// Count the elements in the closed interval `lo..=hi`.
+                pub const fn count_in_range(&self, lo: $ty, hi: $ty) -> usize {
+                    let mut us = *self;
+                    let mut count = 0;
+                    while let Some(it) = next_in_list!(us) {
+                        if lo <= it && it <= hi {
+                            count += 1
+                        }
+                    }
+                    count
+                }
+                /// Returns true if no element appears more than once.
+                pub const fn all_unique(&self) -> bool {
+                    let mut us = *self;
+                    while let Some(it) = next_in_list!(us) {
+                        let mut later = us;
+                        while let Some(other) = next_in_list!(later) {
+                            if other == it {
+                                return false;
+                            }
+                        }
+                    }
+                    true
+                }
+                /// Count the number of distinct elements.
+                pub const fn count_distinct(&self) -> usize {
+                    let mut us = *self;
+                    let mut count = 0;
+                    'outer: while let Some(it) = next_in_list!(us) {
+                        // only count the last occurrence of each element
+                        let mut later = us;
+                        while let Some(other) = next_in_list!(later) {
+                            if other == it {
+                                continue 'outer;
+                            }
+                        }
+                        count += 1;
+                    }
+                    count
+                }
+                /// Copy the elements into an array, skipping any that have already been seen.
+                ///
+                /// Returns the array and the number of unique elements,
+                /// or [`None`] if there are more than `N` unique elements.
+                /// Unused trailing elements are set to `
+                #[doc = stringify!($default)]
+                /// `.
+                pub const fn dedup_into_array<const N: usize>(&self) -> Option<([$ty; N], usize)> {
+                    let mut us = *self;
+                    let mut array = [$default; N];
+                    let mut count = 0;
+                    'outer: while let Some(it) = next_in_list!(us) {
+                        let mut ix = 0;
+                        while ix < count {
+                            if array[ix] == it {
+                                continue 'outer;
+                            }
+                            ix += 1;
+                        }
+                        if count == N {
+                            return None;
+                        }
+                        array[count] = it;
+                        count += 1;
+                    }
+                    Some((array, count))
+                }
+                /// Copy the elements into an array, rotated left by `by` places (modulo `N`).
+                ///
+                /// Returns [`None`] if the list does not have exactly `N` elements.
+                pub const fn rotate_left_array<const N: usize>(
+                    &self,
+                    by: usize,
+                ) -> Option<[$ty; N]> {
+                    if self.len() != N {
+                        return None;
+                    }
+                    let mut us = *self;
+                    let mut array = [$default; N];
+                    let mut ix = 0;
+                    while let Some(it) = next_in_list!(us) {
+                        // N is non-zero if there are elements
+                        array[(ix + N - by % N) % N] = it;
+                        ix += 1;
+                    }
+                    Some(array)
+                }
+                /// Run-length encode the list into an array of `(value, run_length)` pairs,
+                /// one for each maximal run of equal elements.
+                ///
+                /// Returns the array and the number of runs,
+                /// or [`None`] if there are more than `N` runs.
+                /// Unused trailing pairs are set to `(
+                #[doc = stringify!($default)]
+                /// , 0)`.
+                pub const fn runs_into_array<const N: usize>(
+                    &self,
+                ) -> Option<([($ty, usize); N], usize)> {
+                    let mut us = *self;
+                    let mut array = [($default, 0); N];
+                    let mut count = 0;
+                    while let Some(it) = next_in_list!(us) {
+                        if count > 0 && array[count - 1].0 == it {
+                            array[count - 1].1 += 1;
+                            continue;
+                        }
+                        if count == N {
+                            return None;
+                        }
+                        array[count] = (it, 1);
+                        count += 1;
+                    }
+                    Some((array, count))
+                }
+                /// Returns true if the elements are in non-increasing order.
+                ///
+                /// This is the [`core::cmp::Reverse`] of [`Self::is_sorted`].
+                pub const fn is_sorted_desc(&self) -> bool {
+                    let mut us = *self;
+                    let Some(mut prev) = next_in_list!(us) else {
+                        return true;
+                    };
+                    while let Some(it) = next_in_list!(us) {
+                        if prev < it {
+                            return false;
+                        }
+                        prev = it;
+                    }
+                    true
+                }
+                /// Return the index of the first element that is not equal to `elem`,
+                /// or [`None`] if all elements are equal to it.
+                pub const fn find_first_not(&self, elem: &$ty) -> Option<usize> {
+                    let mut us = *self;
+                    let mut ix = 0;
+                    while let Some(it) = next_in_list!(us) {
+                        if it != *elem {
+                            return Some(ix);
+                        }
+                        ix += 1;
+                    }
+                    None
+                }
+                /// Returns true if the elements are in non-decreasing order.
+                ///
+                /// See [`Self::is_sorted_desc`] for non-increasing order.
+                pub const fn is_sorted(&self) -> bool {
+                    let mut us = *self;
+                    let Some(mut prev) = next_in_list!(us) else {
+                        return true;
+                    };
+                    while let Some(it) = next_in_list!(us) {
+                        if prev > it {
+                            return false;
+                        }
+                        prev = it;
+                    }
+                    true
+                }
+                /// Search a sorted list for `needle`, like [`slice::binary_search`].
+                ///
+                /// Returns [`Ok`] with the index of a matching element,
+                /// or [`Err`] with the index where `needle` could be inserted to keep the list sorted.
+                /// If the list is not sorted (see [`Self::is_sorted`]), the result is unspecified.
+                ///
+                /// This makes `O(log n)` comparisons, but must still walk up to `n` elements.
+                pub const fn binary_search(&self, needle: $ty) -> Result<usize, usize> {
+                    let mut base = *self;
+                    let mut offset = 0;
+                    let mut size = self.len();
+                    while size > 0 {
+                        let half = size / 2;
+                        let Some((mid, rest)) = base.skip(half).split_first() else {
+                            break;
+                        };
+                        if *mid == needle {
+                            return Ok(offset + half);
+                        }
+                        match *mid < needle {
+                            true => {
+                                base = rest;
+                                offset += half + 1;
+                                size -= half + 1;
+                            }
+                            false => size = half,
+                        }
+                    }
+                    Err(offset)
+                }
+                /// Returns true if the list reads the same forwards and backwards.
+                ///
+                /// Empty and single-element lists are palindromes.
+                pub const fn is_palindrome(&self) -> bool {
+                    // The back half is copied into a buffer a chunk at a time, from the end,
+                    // so lists of up to twice the chunk size are only walked once.
+                    const CHUNK: usize = 64;
+                    let back = self.len() - self.len() / 2;
+                    let mut front = *self;
+                    let mut end = self.len();
+                    while end > back {
+                        let start = match end.checked_sub(CHUNK) {
+                            Some(start) if start > back => start,
+                            _ => back,
+                        };
+                        let (buf, _) = self.skip(start).copy_prefix::<CHUNK>($default);
+                        let mut ix = end - start;
+                        while let Some(nix) = ix.checked_sub(1) {
+                            match next_in_list!(front) {
+                                Some(it) if it == buf[nix] => ix = nix,
+                                _ => return false,
+                            }
+                        }
+                        end = start;
+                    }
+                    true
+                }
+                /// Return the index of the first occurrence of `needle`.
+                ///
+                /// An empty `needle` is found at index `0`.
+                pub const fn find_subslice(&self, needle: &[$ty]) -> Option<usize> {
+                    let mut start = *self;
+                    let mut ix = 0;
+                    loop {
+                        if start.starts_with(needle) {
+                            return Some(ix);
+                        }
+                        match start.split_first() {
+                            Some((_, next)) => start = next,
+                            None => return None,
+                        }
+                        ix += 1;
+                    }
+                }
+                /// Count the (possibly overlapping) occurrences of `needle`.
+                ///
+                /// An empty `needle` matches at every index, including [`List::len`].
+                pub const fn count_subslice(&self, needle: &[$ty]) -> usize {
+                    let mut start = *self;
+                    let mut count = 0;
+                    loop {
+                        if start.starts_with(needle) {
+                            count += 1
+                        }
+                        match start.split_first() {
+                            Some((_, next)) => start = next,
+                            None => return count,
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    for_all_const_types!(impl_primitive);
+
+    macro_rules! impl_int {
+        ($name:ident/$nil:ident for $ty:ty = $default:expr) => {
+            impl List<'_, $ty> {
+                /// Add all the elements together, returning [`None`] on overflow.
+                ///
+                /// The sum of an empty list is `0`.
+                pub const fn checked_sum(&self) -> Option<$ty> {
+                    let mut us = *self;
+                    let mut sum: $ty = 0;
+                    while let Some(it) = next_in_list!(us) {
+                        sum = match sum.checked_add(it) {
+                            Some(it) => it,
+                            None => return None,
+                        }
+                    }
+                    Some(sum)
+                }
+                /// Multiply all the elements together, returning [`None`] on overflow.
+                ///
+                /// The product of an empty list is `1`.
+                pub const fn checked_product(&self) -> Option<$ty> {
+                    let mut us = *self;
+                    let mut product: $ty = 1;
+                    while let Some(it) = next_in_list!(us) {
+                        product = match product.checked_mul(it) {
+                            Some(it) => it,
+                            None => return None,
+                        }
+                    }
+                    Some(product)
+                }
+                /// Return the smallest element, or [`None`] if the list is empty.
+                ///
+                /// This takes `self` by value so that it isn't shadowed by [`Ord::min`].
+                pub const fn min(self) -> Option<$ty> {
+                    let mut us = self;
+                    let Some(mut min) = next_in_list!(us) else {
+                        return None;
+                    };
+                    while let Some(it) = next_in_list!(us) {
+                        if it < min {
+                            min = it
+                        }
+                    }
+                    Some(min)
+                }
+                /// Return the largest element, or [`None`] if the list is empty.
+                ///
+                /// This takes `self` by value so that it isn't shadowed by [`Ord::max`].
+                pub const fn max(self) -> Option<$ty> {
+                    let mut us = self;
+                    let Some(mut max) = next_in_list!(us) else {
+                        return None;
+                    };
+                    while let Some(it) = next_in_list!(us) {
+                        if it > max {
+                            max = it
+                        }
+                    }
+                    Some(max)
+                }
+                /// Sum the squares of the elements, wrapping around on overflow.
+                pub const fn sum_of_squares(&self) -> $ty {
+                    let mut us = *self;
+                    let mut sum: $ty = 0;
+                    while let Some(it) = next_in_list!(us) {
+                        sum = sum.wrapping_add(it.wrapping_mul(it))
+                    }
+                    sum
+                }
+                /// Multiply all the elements together, saturating at the numeric bounds.
+                ///
+                /// The product of an empty list is `1`.
+                pub const fn saturating_product(&self) -> $ty {
+                    let mut us = *self;
+                    let mut product: $ty = 1;
+                    while let Some(it) = next_in_list!(us) {
+                        product = product.saturating_mul(it)
+                    }
+                    product
+                }
+            }
+        };
+    }
+
+    for_all_int_types!(impl_int);
+
+    macro_rules! impl_signed {
+        ($name:ident/$nil:ident for $ty:ty = $default:expr) => {
+            impl List<'_, $ty> {
+                /// Sum the absolute values of the elements, wrapping around on overflow.
+                ///
+                /// Note that the absolute value of [`
+                #[doc = stringify!($ty)]
+                /// ::MIN`] wraps to itself, see [`
+                #[doc = stringify!($ty)]
+                /// ::wrapping_abs`].
+                pub const fn abs_sum(&self) -> $ty {
+                    let mut us = *self;
+                    let mut sum: $ty = 0;
+                    while let Some(it) = next_in_list!(us) {
+                        sum = sum.wrapping_add(it.wrapping_abs())
+                    }
+                    sum
+                }
+            }
+        };
+    }
+
+    for_all_signed_types!(impl_signed);
 
     macro_rules! define {
-        ($name:ident/$nil:ident for $ty:ty) => {
+        ($name:ident/$nil:ident for $ty:ty = $default:expr) => {
             /// A [`
             #[doc = stringify!($ty)]
             /// `] element in a [`TypeSlice`].
@@ -328,60 +2823,1606 @@ pub mod types {
                 _phantom: PhantomData<fn() -> Rest>,
             }
 
-            /// A terminating element in a [`TypeSlice`] of [`
-            #[doc = stringify!($ty)]
-            /// `].
-            ///
-            /// This is not common between [`TypeSlice`] types to aid type inference in
-            /// edge cases.
-            #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-            pub enum $nil {}
+            /// A terminating element in a [`TypeSlice`] of [`
+            #[doc = stringify!($ty)]
+            /// `].
+            ///
+            /// This is not common between [`TypeSlice`] types to aid type inference in
+            /// edge cases.
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            pub enum $nil {}
+
+            impl<const ELEM: $ty, Rest: TypeSlice<$ty>> TypeSlice<$ty> for $name<ELEM, Rest> {
+                const LIST: List<'static, $ty> = List::cons(&ELEM, &Rest::LIST);
+                const LEN: usize = 1 + Rest::LEN;
+            }
+
+            impl TypeSlice<$ty> for $nil {
+                const LIST: List<'static, $ty> = List::Empty;
+                const LEN: usize = 0;
+            }
+
+            impl<const ELEM: $ty, Rest: TypeSlice<$ty>> SortedSlice<$ty> for $name<ELEM, Rest> {
+                const IS_SORTED: bool = <Self as TypeSlice<$ty>>::LIST.is_sorted();
+            }
+
+            impl SortedSlice<$ty> for $nil {
+                // An empty slice is sorted.
+                const IS_SORTED: bool = true;
+            }
+        };
+    }
+    for_all_const_types!(define);
+
+    /// A [`TypeSlice`] member of a [`TypeGroup`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Group<Head, Rest> {
+        _never: Never,
+        _phantom: PhantomData<fn() -> (Head, Rest)>,
+    }
+
+    /// A terminating element in a [`TypeGroup`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub enum GroupNil {}
+
+    impl<T: 'static, Head: TypeSlice<T>, Rest: TypeGroup<T>> TypeGroup<T> for Group<Head, Rest> {
+        const LISTS: List<'static, List<'static, T>> = List::cons(&Head::LIST, &Rest::LISTS);
+        const LEN: usize = 1 + Rest::LEN;
+    }
+
+    impl<T: 'static> TypeGroup<T> for GroupNil {
+        const LISTS: List<'static, List<'static, T>> = List::Empty;
+        const LEN: usize = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::*;
+    use static_assertions::{assert_type_eq_all, const_assert, const_assert_eq};
+
+    type Empty = U8Nil;
+    type Hello = U8<b'h', U8<b'e', U8<b'l', U8<b'l', U8<b'o', U8Nil>>>>>;
+
+    const_assert!(Empty::LIST.slice_eq(b""));
+    const_assert_eq!(Empty::LEN, 0);
+    const_assert!(Hello::LIST.slice_eq(b"hello"));
+    const_assert!(!Hello::LIST.slice_eq(b"hellO"));
+    const_assert!(!Hello::LIST.slice_eq(b"hell"));
+    const_assert!(!Empty::LIST.slice_eq(b"h"));
+    const_assert_eq!(Hello::LEN, 5);
+
+    type Empty2 = u8![];
+    type Hello2 = u8![0x68, 0x65, 0x6c, 0x6c, 0x6f];
+
+    const_assert!(Empty2::LIST.slice_eq(b""));
+    const_assert_eq!(Empty2::LEN, 0);
+    const_assert!(Hello2::LIST.slice_eq(b"hello"));
+    const_assert_eq!(Hello2::LEN, 5);
+
+    const_assert_eq!(Hello::LIST.longest_common_prefix(b"help"), 3);
+    const_assert_eq!(Hello::LIST.longest_common_prefix(b"hello, world"), 5);
+    const_assert_eq!(Hello::LIST.longest_common_prefix(b"hell"), 4);
+    const_assert_eq!(Hello::LIST.longest_common_prefix(b"jello"), 0);
+    const_assert_eq!(Empty::LIST.longest_common_prefix(b"a"), 0);
+
+    const_assert!(Hello::LIST.starts_with(b"he"));
+    const_assert!(Hello::LIST.starts_with(b"hello"));
+    const_assert!(Hello::LIST.starts_with(b""));
+    const_assert!(!Hello::LIST.starts_with(b"hello!"));
+    const_assert!(!Hello::LIST.starts_with(b"el"));
+    const_assert!(Hello::LIST.ends_with(b"lo"));
+    const_assert!(Hello::LIST.ends_with(b"hello"));
+    const_assert!(Hello::LIST.ends_with(b""));
+    const_assert!(!Hello::LIST.ends_with(b"!hello"));
+    const_assert!(!Hello::LIST.ends_with(b"l"));
+    const_assert!(Empty::LIST.starts_with(b"") && Empty::LIST.ends_with(b""));
+
+    const _: () = {
+        type Tag = char!['n', 's', ':', 'i', 'd'];
+        let Some(rest) = Tag::LIST.strip_prefix(&['n', 's', ':']) else {
+            panic!()
+        };
+        assert!(rest.slice_eq(&['i', 'd']));
+        assert!(Tag::LIST.strip_prefix(&['x']).is_none());
+        let Some(rest) = Tag::LIST.strip_prefix(&[]) else {
+            panic!()
+        };
+        assert!(rest.len() == 5);
+
+        let Some(init) = Tag::LIST.strip_suffix(&[':', 'i', 'd']) else {
+            panic!()
+        };
+        assert!(init.len() == 2);
+        assert!(matches!(init.get(1), Some('s')));
+        assert!(Tag::LIST.strip_suffix(&['x']).is_none());
+        let Some(init) = Tag::LIST.strip_suffix(&['n', 's', ':', 'i', 'd']) else {
+            panic!()
+        };
+        assert!(init.is_empty());
+    };
+
+    const_assert!(Hello::LIST.contains(b'l'));
+    const_assert!(!Hello::LIST.contains(b'x'));
+    const_assert!(<char!['x', 'y']>::LIST.contains('x'));
+    const_assert!(!Empty::LIST.contains(0));
+
+    const_assert!(matches!(Hello::LIST.position(b'l'), Some(2)));
+    const_assert!(matches!(Hello::LIST.rposition(b'l'), Some(3)));
+    const_assert!(matches!(Hello::LIST.rposition(b'h'), Some(0)));
+    const_assert!(Hello::LIST.position(b'x').is_none());
+    const_assert!(Hello::LIST.rposition(b'x').is_none());
+    const_assert!(Empty::LIST.position(0).is_none());
+
+    const_assert_eq!(Hello::LIST.count_of(b'l'), 2);
+    const_assert_eq!(Hello::LIST.count_of(b'h'), 1);
+    const_assert_eq!(Hello::LIST.count_of(b'x'), 0);
+    const_assert_eq!(<char!['a', ':', 'b']>::LIST.count_of(':'), 1);
+
+    const_assert!(Hello::LIST.slice_cmp(b"hello").is_eq());
+    const_assert!(Hello::LIST.slice_cmp(b"help").is_lt());
+    const_assert!(Hello::LIST.slice_cmp(b"hell").is_gt());
+    const_assert!(Hello::LIST.slice_cmp(b"hello!").is_lt());
+    const_assert!(Hello::LIST.slice_cmp(b"a").is_gt());
+    const_assert!(Empty::LIST.slice_cmp(b"").is_eq());
+    const_assert!(Empty::LIST.slice_cmp(b"a").is_lt());
+
+    type Mixed = i32![-5, 0, 3, 10];
+
+    const_assert_eq!(Mixed::LIST.count_in_range(0, 5), 2);
+    const_assert_eq!(Mixed::LIST.count_in_range(-5, 10), 4);
+    const_assert_eq!(Mixed::LIST.count_in_range(5, 0), 0);
+    const_assert_eq!(Empty::LIST.count_in_range(0, 255), 0);
+
+    const_assert!(<char!['a', 'b', 'a']>::LIST.is_palindrome());
+    const_assert!(!<char!['a', 'b']>::LIST.is_palindrome());
+    const_assert!(<char!['a', 'b', 'b', 'a']>::LIST.is_palindrome());
+    const_assert!(<char!['a']>::LIST.is_palindrome());
+    const_assert!(CharNil::LIST.is_palindrome());
+    const_assert!(!Hello::LIST.is_palindrome());
+    const _: () = {
+        // longer than one chunk of the back half
+        let mut items = [0u8; 301];
+        let mut ix = 0;
+        while ix < items.len() {
+            items[ix] = match ix < 150 {
+                true => ix as u8,
+                false => (300 - ix) as u8,
+            };
+            ix += 1;
+        }
+        assert!(List::from_slice(&items).is_palindrome());
+        assert!(!List::from_slice(items.split_at(300).0).is_palindrome());
+        items[7] = 0;
+        assert!(!List::from_slice(&items).is_palindrome());
+    };
+
+    const _: () = {
+        type Ring = u8![1, 2, 3, 4];
+        assert!(matches!(
+            Ring::LIST.rotate_left_array::<4>(1),
+            Some([2, 3, 4, 1])
+        ));
+        assert!(matches!(
+            Ring::LIST.rotate_left_array::<4>(0),
+            Some([1, 2, 3, 4])
+        ));
+        assert!(matches!(
+            Ring::LIST.rotate_left_array::<4>(7),
+            Some([4, 1, 2, 3])
+        ));
+        assert!(Ring::LIST.rotate_left_array::<3>(1).is_none());
+        assert!(matches!(Empty::LIST.rotate_left_array::<0>(1), Some([])));
+    };
+
+    const _: () = {
+        type Runs = u8![1, 1, 2, 3, 3, 3];
+        let Some((runs, 3)) = Runs::LIST.runs_into_array::<3>() else {
+            panic!()
+        };
+        assert!(matches!(runs, [(1, 2), (2, 1), (3, 3)]));
+        let Some((runs, 3)) = Runs::LIST.runs_into_array::<4>() else {
+            panic!()
+        };
+        assert!(matches!(runs, [(1, 2), (2, 1), (3, 3), (0, 0)]));
+        assert!(Runs::LIST.runs_into_array::<2>().is_none());
+        assert!(matches!(Empty::LIST.runs_into_array::<0>(), Some(([], 0))));
+    };
+
+    const_assert!(<u8![1, 2, 3]>::IS_SORTED);
+    const_assert!(!<u8![3, 1, 2]>::IS_SORTED);
+    const_assert!(<u8![1, 1, 2]>::IS_SORTED);
+    const_assert!(<u8![1]>::IS_SORTED);
+    const_assert!(Empty::IS_SORTED);
+    const_assert!(<char!['a', 'b']>::IS_SORTED);
+    const_assert!(!<bool![true, false]>::IS_SORTED);
+
+    const fn is_sorted<S: SortedSlice<i32>>() -> bool {
+        S::IS_SORTED
+    }
+    const_assert!(is_sorted::<i32![-1, 0, 1]>());
+    const_assert!(!is_sorted::<i32![1, 0]>());
+    const_assert!(is_sorted::<I32Nil>());
+    type Table = i32![-10, 0, 7, 7, 100];
+
+    const_assert!(Table::LIST.is_sorted() && !Table::LIST.is_sorted_desc());
+    const_assert!(<char!['c', 'b', 'a']>::LIST.is_sorted_desc());
+    const_assert!(<bool![true, true, false]>::LIST.is_sorted_desc());
+    const_assert!(<u8![5, 3, 3, 1]>::LIST.is_sorted_desc());
+    const_assert!(!<u8![1, 2]>::LIST.is_sorted_desc());
+    const_assert!(<u8![1]>::LIST.is_sorted_desc());
+    const_assert!(Empty::LIST.is_sorted_desc());
+    const_assert!(<u8![1, 2, 3]>::LIST.is_sorted());
+    const_assert!(!Hello::LIST.is_sorted());
+
+    const_assert!(matches!(
+        <u8![0, 0, 0, 5, 6]>::LIST.find_first_not(&0),
+        Some(3)
+    ));
+    const_assert!(matches!(<u8![5, 0]>::LIST.find_first_not(&0), Some(0)));
+    const_assert!(<u8![0, 0]>::LIST.find_first_not(&0).is_none());
+    const_assert!(Empty::LIST.find_first_not(&0).is_none());
+
+    const_assert!(<u8![0x01, 0x02, 0xff]>::LIST.all_unique());
+    const_assert!(!<u8![0x01, 0x02, 0x01]>::LIST.all_unique());
+    const_assert!(!Hello::LIST.all_unique());
+    const_assert!(Empty::LIST.all_unique());
+    const_assert_eq!(<u8![1, 2, 2, 3, 3, 3]>::LIST.count_distinct(), 3);
+    const_assert_eq!(Hello::LIST.count_distinct(), 4);
+    const_assert_eq!(Empty::LIST.count_distinct(), 0);
+
+    const_assert_eq!(<i8![-3, 4]>::LIST.abs_sum(), 7);
+    const_assert_eq!(<i8![-3, 4]>::LIST.sum_of_squares(), 25);
+    const_assert_eq!(<i8![-128]>::LIST.abs_sum(), -128);
+    const_assert_eq!(<i8![-100, 100]>::LIST.abs_sum(), -56);
+    const_assert_eq!(<u8![16]>::LIST.sum_of_squares(), 0);
+    const_assert_eq!(I8Nil::LIST.abs_sum(), 0);
+    const_assert_eq!(Empty::LIST.sum_of_squares(), 0);
+
+    const_assert!(Hello::LIST.skip(0).slice_eq(b"hello"));
+    const_assert!(Hello::LIST.skip(3).slice_eq(b"lo"));
+    const_assert!(Hello::LIST.skip(5).slice_eq(b""));
+    const_assert!(Hello::LIST.skip(usize::MAX).slice_eq(b""));
+    const_assert!(Empty::LIST.skip(1).slice_eq(b""));
+
+    const _: () = {
+        let Some((b'h', rest)) = Hello::LIST.split_first() else {
+            panic!()
+        };
+        assert!(rest.slice_eq(b"ello"));
+        assert!(Empty::LIST.split_first().is_none());
+
+        let Some((b'o', init)) = Hello::LIST.split_last() else {
+            panic!()
+        };
+        assert!(init.len() == 4);
+        assert!(matches!(init.get(3), Some(b'l')));
+        assert!(init.get(4).is_none());
+        let Some((b'l', init)) = init.split_last() else {
+            panic!()
+        };
+        assert!(init.len() == 3);
+        let Some((1, init)) = <u8![1]>::LIST.split_last() else {
+            panic!()
+        };
+        assert!(init.is_empty() && init.split_last().is_none());
+        assert!(Empty::LIST.split_last().is_none());
+    };
+
+    const _: () = {
+        assert!(matches!(Hello::LIST.first(), Some(b'h')));
+        assert!(matches!(Hello::LIST.last(), Some(b'o')));
+        assert!(matches!(<u8![1]>::LIST.first(), Some(1)));
+        assert!(matches!(<u8![1]>::LIST.last(), Some(1)));
+        assert!(Empty::LIST.first().is_none());
+        assert!(Empty::LIST.last().is_none());
+    };
+
+    const _: () = {
+        type Tens = u8![10, 20, 30];
+        assert!(matches!(Tens::LIST.nth_from_end(0), Some(30)));
+        assert!(matches!(Tens::LIST.nth_from_end(2), Some(10)));
+        assert!(Tens::LIST.nth_from_end(3).is_none());
+        assert!(Tens::LIST.nth_from_end(usize::MAX).is_none());
+        assert!(Empty::LIST.nth_from_end(0).is_none());
+    };
+
+    const_assert!(Hello::LIST.eq_ignore_ascii_case(b"HeLLo"));
+    const_assert!(!Hello::LIST.eq_ignore_ascii_case(b"HeLL"));
+    const_assert!(!Hello::LIST.eq_ignore_ascii_case(b"HeLLo!"));
+    const_assert!(!Hello::LIST.eq_ignore_ascii_case(b"jello"));
+    const_assert!(Empty::LIST.eq_ignore_ascii_case(b""));
+    const_assert!(<char!['É', 'a']>::LIST.eq_ignore_ascii_case(&['É', 'A']));
+    const_assert!(!<char!['É', 'a']>::LIST.eq_ignore_ascii_case(&['é', 'A']));
+
+    const_assert!(Hello::LIST.is_ascii());
+    const_assert!(!<u8![b'a', 0x80]>::LIST.is_ascii());
+    const_assert!(Empty::LIST.is_ascii());
+    const_assert!(<char!['a', '~', '\0']>::LIST.is_ascii());
+    const_assert!(!<char!['a', 'é']>::LIST.is_ascii());
+
+    const_assert!(matches!(<u8![0x01, 0x02]>::LIST.to_u64_be(), Some(0x0102)));
+    const_assert!(matches!(<u8![0x01, 0x02]>::LIST.to_u64_le(), Some(0x0201)));
+    const_assert!(matches!(
+        <u8![1, 2, 3, 4, 5, 6, 7, 8]>::LIST.to_u64_be(),
+        Some(0x0102030405060708)
+    ));
+    const_assert!(matches!(
+        <u8![1, 2, 3, 4, 5, 6, 7, 8]>::LIST.to_u64_le(),
+        Some(0x0807060504030201)
+    ));
+    const_assert!(<u8![1, 2, 3, 4, 5, 6, 7, 8, 9]>::LIST.to_u64_be().is_none());
+    const_assert!(<u8![1, 2, 3, 4, 5, 6, 7, 8, 9]>::LIST.to_u64_le().is_none());
+    const_assert!(matches!(Empty::LIST.to_u64_be(), Some(0)));
+
+    type Registers = u32![0x4000_0010, 0x4000_0000, 0x4000_00ff];
+
+    const_assert!(matches!(Registers::LIST.min(), Some(0x4000_0000)));
+    const_assert!(matches!(Registers::LIST.max(), Some(0x4000_00ff)));
+    const_assert!(matches!(<i8![-3, 4]>::LIST.min(), Some(-3)));
+    const_assert!(matches!(<i8![-3, 4]>::LIST.max(), Some(4)));
+    const_assert!(Empty::LIST.min().is_none());
+    const_assert!(Empty::LIST.max().is_none());
+
+    const_assert!(matches!(
+        <usize![2, 4, 1500]>::LIST.checked_sum(),
+        Some(1506)
+    ));
+    const_assert!(matches!(<u8![200, 55]>::LIST.checked_sum(), Some(255)));
+    const_assert!(<u8![200, 56]>::LIST.checked_sum().is_none());
+    const_assert!(matches!(<i8![-128, 127]>::LIST.checked_sum(), Some(-1)));
+    const_assert!(<i8![-128, 127, 127, 127]>::LIST.checked_sum().is_none());
+    const_assert!(matches!(Empty::LIST.checked_sum(), Some(0)));
+
+    type Sixteens = u8![16, 16];
+
+    const_assert!(Hello2::LIST.checked_product().is_none());
+    const_assert!(Sixteens::LIST.checked_product().is_none());
+    const_assert_eq!(Sixteens::LIST.saturating_product(), 255);
+    const_assert!(matches!(<u8![15, 17]>::LIST.checked_product(), Some(255)));
+    const_assert!(matches!(Empty::LIST.checked_product(), Some(1)));
+    const_assert_eq!(Empty::LIST.saturating_product(), 1);
+    const_assert!(<i8![-16, 16]>::LIST.checked_product().is_none());
+    const_assert!(matches!(<i8![-16, 8]>::LIST.checked_product(), Some(-128)));
+    const_assert_eq!(<i8![-16, 16]>::LIST.saturating_product(), -128);
+    const_assert!(matches!(<i8![-2, 64]>::LIST.checked_product(), Some(-128)));
+
+    const_assert!(matches!(Hello::LIST.find_subslice(b"ll"), Some(2)));
+    const_assert!(matches!(Hello::LIST.find_subslice(b"hello"), Some(0)));
+    const_assert!(matches!(Hello::LIST.find_subslice(b"o"), Some(4)));
+    const_assert!(matches!(Hello::LIST.find_subslice(b""), Some(0)));
+    const_assert!(Hello::LIST.find_subslice(b"lol").is_none());
+    const_assert!(Hello::LIST.find_subslice(b"hello!").is_none());
+    const_assert!(matches!(Empty::LIST.find_subslice(b""), Some(0)));
+
+    type Overlapping = u8![1, 1, 1, 2, 1, 1];
+
+    const_assert_eq!(Overlapping::LIST.count_subslice(&[1, 1]), 3);
+    const_assert_eq!(Overlapping::LIST.count_subslice(&[1, 2, 1]), 1);
+    const_assert_eq!(Overlapping::LIST.count_subslice(&[2, 2]), 0);
+    const_assert_eq!(Overlapping::LIST.count_subslice(&[1, 1, 1, 2, 1, 1, 1]), 0);
+    const_assert_eq!(Overlapping::LIST.count_subslice(&[]), 7);
+    const_assert_eq!(Hello::LIST.count_subslice(b"l"), 2);
+    const_assert_eq!(Empty::LIST.count_subslice(b"l"), 0);
+
+    const _: () = {
+        type Multiset = u8![1, 2, 1, 3, 2];
+        let Some((set, 3)) = Multiset::LIST.dedup_into_array::<3>() else {
+            panic!()
+        };
+        assert!(matches!(set, [1, 2, 3]));
+        let Some((set, 3)) = Multiset::LIST.dedup_into_array::<4>() else {
+            panic!()
+        };
+        assert!(matches!(set, [1, 2, 3, 0]));
+        assert!(Multiset::LIST.dedup_into_array::<2>().is_none());
+        assert!(matches!(Empty::LIST.dedup_into_array::<0>(), Some(([], 0))));
+    };
+
+    type Abcd = char!['a', 'b', 'c', 'd'];
+
+    assert_type_eq_all!(<Abcd as ops::Take<0>>::Output, char![]);
+    assert_type_eq_all!(<Abcd as ops::Take<2>>::Output, char!['a', 'b']);
+    assert_type_eq_all!(<Abcd as ops::Take<4>>::Output, Abcd);
+    assert_type_eq_all!(<Abcd as ops::Take<5>>::Output, Abcd);
+    assert_type_eq_all!(<Abcd as ops::Skip<0>>::Output, Abcd);
+    assert_type_eq_all!(<Abcd as ops::Skip<2>>::Output, char!['c', 'd']);
+    assert_type_eq_all!(<Abcd as ops::Skip<4>>::Output, char![]);
+    assert_type_eq_all!(<Abcd as ops::Skip<5>>::Output, char![]);
+    assert_type_eq_all!(<Empty as ops::Skip<{ usize::MAX }>>::Output, Empty);
+
+    assert_type_eq_all!(
+        <Abcd as ops::Concat<char!['e']>>::Output,
+        char!['a', 'b', 'c', 'd', 'e']
+    );
+    assert_type_eq_all!(<Abcd as ops::Concat<char![]>>::Output, Abcd);
+    assert_type_eq_all!(<char![] as ops::Concat<Abcd>>::Output, Abcd);
+
+    type Long = u8![
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+        25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47,
+        48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64
+    ];
+    assert_type_eq_all!(<Long as ops::Skip<{ ops::MAX_COUNT }>>::Output, u8![64]);
+    const_assert_eq!(
+        <Long as ops::Take<{ ops::MAX_COUNT }>>::Output::LEN,
+        ops::MAX_COUNT
+    );
+
+    #[test]
+    fn test() {
+        itertools::assert_equal(Empty::LIST, b"");
+        itertools::assert_equal(Hello::LIST, b"hello");
+        itertools::assert_equal(Empty2::LIST, b"");
+        itertools::assert_equal(Hello2::LIST, b"hello");
+    }
+
+    #[test]
+    fn try_for_each() {
+        let mut seen = 0;
+        let res = Hello::LIST.try_for_each(|it| match *it != b'l' {
+            true => {
+                seen += 1;
+                Ok(())
+            }
+            false => Err(*it),
+        });
+        assert_eq!(res, Err(b'l'));
+        assert_eq!(seen, 2);
+        assert_eq!(Hello::LIST.try_for_each(|_| Ok::<_, ()>(())), Ok(()));
+    }
+
+    #[test]
+    fn eq_slice() {
+        #[derive(Debug, PartialEq)]
+        struct NotConst(&'static str);
+
+        const NAMES: List<NotConst> =
+            List::cons(&NotConst("a"), &List::cons(&NotConst("b"), &List::Empty));
+        assert!(NAMES.eq_slice(&[NotConst("a"), NotConst("b")]));
+        assert!(!NAMES.eq_slice(&[NotConst("a")]));
+        assert!(!NAMES.eq_slice(&[NotConst("a"), NotConst("c")]));
+        assert!(Hello::LIST.eq_slice(b"hello"));
+        assert!(Empty::LIST.eq_slice::<u8>(&[]));
+    }
+
+    #[test]
+    fn partial_eq_slice() {
+        assert_eq!(Hello::LIST, b"hello");
+        assert_eq!(Hello::LIST, *b"hello");
+        assert_eq!(Hello::LIST, &b"hello"[..]);
+        assert_eq!(Hello::LIST, b"hello"[..]);
+        assert_ne!(Hello::LIST, b"hell");
+        assert_ne!(Hello::LIST, &b"help"[..]);
+        assert_eq!(Empty::LIST, []);
+        assert_eq!(<char!['a']>::LIST, ['a']);
+    }
+
+    #[test]
+    fn partial_eq_str() {
+        type Message = char!['h', 'i', '👋'];
+        let input = "hi👋";
+        assert!(Message::LIST == *input);
+        assert!(*input == Message::LIST);
+        assert_eq!(Message::LIST, input);
+        assert_eq!(input, Message::LIST);
+        assert_ne!(Message::LIST, "hi");
+        assert_ne!(Message::LIST, "hi👋👋");
+        assert_eq!(CharNil::LIST, "");
+    }
+
+    #[test]
+    fn iter_inspect() {
+        let mut seen = [0; 5];
+        let mut count = 0;
+        let iter = Hello::LIST.iter_inspect(|it| {
+            seen[count] = *it;
+            count += 1;
+        });
+        itertools::assert_equal(iter, b"hello");
+        assert_eq!(count, 5);
+        assert_eq!(&seen, b"hello");
+    }
+
+    #[test]
+    fn fold_indexed() {
+        let weighted = <u32![5, 6, 7]>::LIST.fold_indexed(0, |acc, ix, it| acc + ix as u32 * it);
+        assert_eq!(weighted, 6 + 2 * 7);
+        assert_eq!(U32Nil::LIST.fold_indexed(1, |_, _, _| unreachable!()), 1);
+    }
+
+    #[test]
+    fn try_fold() {
+        type Digits = char!['1', '2', 'x', '3'];
+        let res = Digits::LIST.try_fold(0u32, |acc, c| match c.to_digit(10) {
+            Some(digit) => Ok(acc * 10 + digit),
+            None => Err((acc, *c)),
+        });
+        assert_eq!(res, Err((12, 'x')));
+        let res = <char!['1', '2']>::LIST.try_fold(0u32, |acc, c| {
+            c.to_digit(10).map(|d| acc * 10 + d).ok_or(())
+        });
+        assert_eq!(res, Ok(12));
+    }
+
+    #[test]
+    fn by_key() {
+        type Letters = char!['a', 'Z', 'm'];
+        assert_eq!(Letters::LIST.max_by_key(|c| u32::from(*c)), Some(&'m'));
+        assert_eq!(Letters::LIST.min_by_key(|c| u32::from(*c)), Some(&'Z'));
+        assert_eq!(
+            Letters::LIST.max_by_key(|c| c.to_ascii_lowercase()),
+            Some(&'Z')
+        );
+        assert_eq!(CharNil::LIST.max_by_key(|c| u32::from(*c)), None);
+        assert_eq!(CharNil::LIST.min_by_key(|c| u32::from(*c)), None);
+    }
+
+    #[test]
+    fn split_last() {
+        let (last, init) = Hello::LIST.split_last().unwrap();
+        assert_eq!(last, &b'o');
+        itertools::assert_equal(init, b"hell");
+        assert_eq!(init.iter().count(), 4);
+    }
+
+    #[test]
+    fn double_ended() {
+        itertools::assert_equal(Hello::LIST.iter().rev(), b"olleh");
+        itertools::assert_equal(Empty::LIST.iter().rev(), b"");
+
+        let mut iter = Hello::LIST.iter();
+        assert_eq!(iter.next(), Some(&b'h'));
+        assert_eq!(iter.next_back(), Some(&b'o'));
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert_eq!(iter.next_back(), Some(&b'l'));
+        assert_eq!(iter.next(), Some(&b'e'));
+        assert_eq!(iter.next(), Some(&b'l'));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        let (_, init) = Hello::LIST.split_last().unwrap();
+        itertools::assert_equal(init.iter().rev(), b"lleh");
+    }
+
+    #[test]
+    fn exact_size() {
+        let mut iter = Hello::LIST.iter();
+        assert_eq!(iter.len(), 5);
+        iter.next_back();
+        assert_eq!(iter.len(), 4);
+        iter.by_ref().for_each(drop);
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+        assert_eq!(Empty::LIST.iter().len(), 0);
+        static_assertions::assert_impl_all!(Iter<'static, u8>: ExactSizeIterator, core::iter::FusedIterator);
+    }
+
+    #[test]
+    fn iter_as_list() {
+        let mut iter = Hello::LIST.iter();
+        iter.next();
+        assert_eq!(iter.as_list(), b"ello");
+        let copy = iter;
+        iter.next();
+        assert_eq!(copy.as_list(), b"ello");
+        assert_eq!(iter.as_list(), b"llo");
+        iter.next_back();
+        assert_eq!(iter.as_list(), b"llo");
+
+        use core::fmt::Write as _;
+        let mut buf = Buf::new();
+        write!(buf, "{:?}", iter).unwrap();
+        assert_eq!(buf.as_str(), "Iter([108, 108])");
+    }
+
+    #[test]
+    fn into_iter_ref() {
+        let mut count = 0;
+        for _ in &Hello::LIST {
+            count += 1
+        }
+        assert_eq!(count, 5);
+        static_assertions::assert_impl_all!(&List<'static, u8>: IntoIterator<Item = &'static u8>);
+    }
+
+    #[test]
+    fn slice_backed() {
+        const LIST: List<u8> = List::from_slice(b"hello");
+        assert_eq!(LIST, Hello::LIST);
+        assert_eq!(LIST, b"hello");
+        assert_eq!(LIST.len(), 5);
+        assert_eq!(LIST.get(4), Some(&b'o'));
+        assert_eq!(LIST.get(5), None);
+        assert_eq!(LIST.skip(2), b"llo");
+        assert_eq!(LIST.skip(6), List::Empty);
+        assert_eq!(LIST.split_last().map(|(it, _)| it), Some(&b'o'));
+        assert!(LIST.iter().rev().eq(b"olleh"));
+        assert_eq!(LIST.cmp(&Hello::LIST), core::cmp::Ordering::Equal);
+        assert!(List::<u8>::from_slice(&[]).is_empty());
+        assert_eq!(List::<u8>::from_slice(&[]), Empty::LIST);
+    }
+
+    #[test]
+    fn windows() {
+        let mut windows = Hello::LIST.windows(3);
+        assert_eq!(windows.next().map(|it| it.len()), Some(3));
+        itertools::assert_equal(Hello::LIST.windows(3).next().unwrap(), b"hel");
+        itertools::assert_equal(Hello::LIST.windows(3).last().unwrap(), b"llo");
+        assert_eq!(Hello::LIST.windows(3).count(), 3);
+        assert_eq!(Hello::LIST.windows(5).count(), 1);
+        assert_eq!(Hello::LIST.windows(6).count(), 0);
+        assert_eq!(Empty::LIST.windows(1).count(), 0);
+    }
+
+    #[test]
+    #[should_panic = "window size must be non-zero"]
+    fn windows_zero() {
+        let _ = Hello::LIST.windows(0);
+    }
+
+    #[test]
+    fn chunks() {
+        let mut chunks = Hello::LIST.chunks(2);
+        itertools::assert_equal(chunks.next().unwrap(), b"he");
+        itertools::assert_equal(chunks.next().unwrap(), b"ll");
+        itertools::assert_equal(chunks.next().unwrap(), b"o");
+        assert!(chunks.next().is_none());
+        assert_eq!(Hello::LIST.chunks(5).count(), 1);
+        assert_eq!(Hello::LIST.chunks(6).count(), 1);
+        assert_eq!(Empty::LIST.chunks(1).count(), 0);
+    }
+
+    #[test]
+    #[should_panic = "chunk size must be non-zero"]
+    fn chunks_zero() {
+        let _ = Hello::LIST.chunks(0);
+    }
+
+    #[test]
+    fn chain() {
+        const CHAINED: Chain<u8> = Hello::LIST.chain(Hello2::LIST);
+        itertools::assert_equal(CHAINED, b"hellohello");
+        itertools::assert_equal(CHAINED.rev(), b"olleholleh");
+        assert_eq!(CHAINED.len(), 10);
+        itertools::assert_equal(Empty::LIST.chain(Hello::LIST), b"hello");
+        itertools::assert_equal(Hello::LIST.chain(Empty::LIST), b"hello");
+
+        let mut chained = CHAINED;
+        assert_eq!(chained.next_back(), Some(&b'o'));
+        chained.by_ref().take(6).for_each(drop);
+        itertools::assert_equal(chained, b"ell");
+    }
+
+    #[test]
+    fn zip() {
+        let zipped = Hello::LIST.zip(Abcd::LIST);
+        assert_eq!(zipped.len(), 4);
+        itertools::assert_equal(
+            zipped.rev(),
+            [(&b'l', &'d'), (&b'l', &'c'), (&b'e', &'b'), (&b'h', &'a')],
+        );
+        assert_eq!(Empty::LIST.zip(Hello::LIST).len(), 0);
+    }
+
+    const _: () = {
+        const HELLO: [u8; 5] = Hello::LIST.to_array();
+        assert!(HELLO[0] == b'h' && HELLO[4] == b'o');
+        const EMPTY: [u8; 0] = Empty::LIST.to_array();
+        assert!(EMPTY.is_empty());
+    };
+
+    #[test]
+    fn copy_to_slice() {
+        let mut buf = [0; 8];
+        assert_eq!(Hello::LIST.copy_to_slice(&mut buf), 5);
+        assert_eq!(buf, *b"hello\0\0\0");
+        let mut buf = [0; 3];
+        assert_eq!(Hello::LIST.copy_to_slice(&mut buf), 3);
+        assert_eq!(buf, *b"hel");
+        assert_eq!(Empty::LIST.copy_to_slice(&mut buf), 0);
+        assert_eq!(Hello::LIST.copy_to_slice(&mut []), 0);
+    }
+
+    #[test]
+    fn nul_terminated() {
+        const HELLO: [u8; 6] = Hello::LIST.to_nul_terminated();
+        assert_eq!(HELLO, *b"hello\0");
+        assert_eq!(Empty::LIST.to_nul_terminated(), [0]);
+
+        let mut buf = [0xFF; 8];
+        let cstr = Hello::LIST.to_cstr_in(&mut buf).unwrap();
+        assert_eq!(cstr.to_bytes(), b"hello");
+        assert_eq!(buf, *b"hello\0\xFF\xFF");
+        assert!(Hello::LIST.to_cstr_in(&mut [0; 5]).is_none());
+        assert!(Hello::LIST.to_cstr_in(&mut [0; 6]).is_some());
+        assert!(<u8![1, 0, 1]>::LIST.to_cstr_in(&mut [0; 8]).is_none());
+    }
+
+    #[test]
+    #[should_panic = "list contains an interior NUL"]
+    fn nul_terminated_interior() {
+        let _ = <u8![1, 0, 1]>::LIST.to_nul_terminated::<4>();
+    }
+
+    #[test]
+    fn to_utf8_array() {
+        type Message = char!['h', 'é', '€', '𓀕'];
+        const ENCODED: ([u8; 12], usize) = Message::LIST.to_utf8_array();
+        assert_eq!(&ENCODED.0[..ENCODED.1], "hé€𓀕".as_bytes());
+        assert_eq!(ENCODED.0[ENCODED.1..], [0; 2]);
+        assert_eq!(CharNil::LIST.to_utf8_array::<0>(), ([], 0));
+
+        for c in (0..=char::MAX as u32).filter_map(char::from_u32) {
+            let mut expected = [0; 4];
+            let (bytes, len) = utf8::encode_utf8(c);
+            assert_eq!(bytes[..len], *c.encode_utf8(&mut expected).as_bytes());
+        }
+    }
+
+    #[test]
+    #[should_panic = "encoded characters do not fit in the array"]
+    fn to_utf8_array_overflow() {
+        let _ = <char!['€']>::LIST.to_utf8_array::<2>();
+    }
+
+    const _: () = {
+        type Primes = u16![2, 3, 5, 7, 11, 13];
+        assert!(Primes::IS_SORTED);
+        assert!(matches!(Primes::LIST.binary_search(11), Ok(4)));
+        assert!(matches!(Primes::LIST.binary_search(2), Ok(0)));
+        assert!(matches!(Primes::LIST.binary_search(1), Err(0)));
+        assert!(matches!(Primes::LIST.binary_search(4), Err(2)));
+        assert!(matches!(Primes::LIST.binary_search(14), Err(6)));
+        assert!(matches!(Abcd::LIST.binary_search('c'), Ok(2)));
+    };
+
+    #[test]
+    fn binary_search() {
+        let haystack = [1, 1, 2, 3, 5, 8, 8, 8, 13];
+        type Fib = i32![1, 1, 2, 3, 5, 8, 8, 8, 13];
+        for needle in -1..15 {
+            let expected = haystack.binary_search(&needle);
+            match (Fib::LIST.binary_search(needle), expected) {
+                (Ok(ix), Ok(_)) => assert_eq!(haystack[ix], needle),
+                (actual, expected) => assert_eq!(actual, expected),
+            }
+        }
+        assert_eq!(Empty::LIST.binary_search(1), Err(0));
+    }
+
+    #[test]
+    fn get_many() {
+        assert_eq!(Hello::LIST.get_many([4, 0, 1]), Some([&b'o', &b'h', &b'e']));
+        assert_eq!(Hello::LIST.get_many([2, 2]), Some([&b'l', &b'l']));
+        assert_eq!(Hello::LIST.get_many([]), Some([]));
+        assert_eq!(Hello::LIST.get_many([0, 5]), None);
+        assert_eq!(Empty::LIST.get_many([]), Some([]));
+        assert_eq!(Empty::LIST.get_many([0]), None);
+    }
 
-            impl<const ELEM: $ty, Rest: TypeSlice<$ty>> TypeSlice<$ty> for $name<ELEM, Rest> {
-                const LIST: List<'static, $ty> = List::Item {
-                    head: &ELEM,
-                    rest: &Rest::LIST,
-                };
-                const LEN: usize = 1 + Rest::LEN;
+    #[test]
+    fn utf8_pop_back() {
+        use utf8::{pop, pop_back, Pop};
+
+        for s in ["a", "é", "€", "𓀕", "a𓀕", "𓀕a", "é€"] {
+            assert_eq!(
+                pop_back(s.as_bytes()),
+                Pop::Ok(s.chars().next_back().unwrap())
+            );
+            assert_eq!(pop(s.as_bytes()), Pop::Ok(s.chars().next().unwrap()));
+        }
+        assert_eq!(pop_back(b""), Pop::Empty);
+        assert_eq!(pop_back(&[0xF0, 0x93, 0x80]), Pop::Truncated);
+        assert_eq!(pop_back(&[b'a', 0x80]), Pop::Invalid);
+        assert_eq!(pop_back(&[0x80, 0x80, 0x80, 0x80]), Pop::Invalid);
+        assert_eq!(pop_back(&[b'a', 0xC3, 0xA9, 0xA9]), Pop::Invalid);
+    }
+
+    #[test]
+    fn chars() {
+        type Message = u8![b'h', 0xC3, 0xA9, 0xF0, 0x93, 0x80, 0x95];
+        itertools::assert_equal(Message::LIST.chars(), "hé𓀕".chars());
+        assert_eq!(Empty::LIST.chars().next(), None);
+
+        type Truncated = u8![b'h', 0xF0, 0x93];
+        let mut chars = Truncated::LIST.chars();
+        assert_eq!(chars.next(), Some('h'));
+        assert_eq!(chars.next(), None);
+        assert_eq!(chars.pop().0, utf8::Pop::Truncated);
+        assert_eq!(chars.as_list(), [0xF0, 0x93]);
+    }
+
+    const _: () = {
+        assert!(Hello::LIST.is_utf8());
+        assert!(Empty::LIST.is_utf8());
+        assert!(!<u8![b'a', 0xC0, 0x80]>::LIST.is_utf8());
+        assert!(matches!(
+            <u8![b'a', 0xE2, 0x82]>::LIST.validate_utf8(),
+            Err(1)
+        ));
+    };
+
+    #[test]
+    fn utf8_pop() {
+        use utf8::{pop, Pop};
+
+        fn expected(bytes: &[u8]) -> Pop {
+            match core::str::from_utf8(bytes) {
+                Ok(s) => s.chars().next().map_or(Pop::Empty, Pop::Ok),
+                Err(e) if e.valid_up_to() > 0 => Pop::Ok(
+                    core::str::from_utf8(&bytes[..e.valid_up_to()])
+                        .unwrap()
+                        .chars()
+                        .next()
+                        .unwrap(),
+                ),
+                Err(e) => match e.error_len() {
+                    Some(_) => Pop::Invalid,
+                    None => Pop::Truncated,
+                },
             }
+        }
 
-            impl TypeSlice<$ty> for $nil {
-                const LIST: List<'static, $ty> = List::Empty;
-                const LEN: usize = 0;
+        /// Like [`String::from_utf8_lossy`].
+        fn expected_lossy(bytes: &[u8]) -> Option<(char, usize)> {
+            match core::str::from_utf8(bytes) {
+                Ok(s) => s.chars().next().map(|c| (c, c.len_utf8())),
+                Err(e) if e.valid_up_to() > 0 => expected_lossy(&bytes[..e.valid_up_to()]),
+                Err(e) => Some((
+                    char::REPLACEMENT_CHARACTER,
+                    e.error_len().unwrap_or(bytes.len()),
+                )),
             }
-        };
+        }
+
+        for x in 0..=u8::MAX {
+            for y in 0..=u8::MAX {
+                for z in [0x00, 0x80, 0x8F, 0x90, 0xBF, 0xC0] {
+                    for bytes in [&[x][..], &[x, y], &[x, y, z], &[x, y, z, 0x80]] {
+                        assert_eq!(pop(bytes), expected(bytes), "{:x?}", bytes);
+                        assert_eq!(
+                            utf8::pop_lossy(bytes),
+                            expected_lossy(bytes),
+                            "{:x?}",
+                            bytes
+                        );
+                    }
+                }
+            }
+        }
     }
-    for_all_const_types!(define);
+
+    const _: () = {
+        assert!(matches!(Hello::LIST.char_count(), Some(5)));
+        assert!(matches!(Empty::LIST.char_count(), Some(0)));
+        assert!(matches!(
+            <u8![0xC3, 0xA9, 0xC3, 0xA9]>::LIST.char_count(),
+            Some(2)
+        ));
+        assert!(<u8![0xC3, 0xA9, 0xC3]>::LIST.char_count().is_none());
+    };
+
+    const_assert_eq!(Abcd::LIST.utf8_len(), 4);
+    const_assert_eq!(CharNil::LIST.utf8_len(), 0);
+    const_assert_eq!(<char!['h', 'é', '€', '𓀕']>::LIST.utf8_len(), 10);
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn chars_lossy() {
+        type Bytes = u8![0xF0, 0x93, b'a', 0xC0, 0x80, 0xED, 0xA0, 0x80, 0xE2, 0x82];
+        let bytes = Bytes::LIST.to_vec();
+        itertools::assert_equal(
+            Bytes::LIST.chars_lossy(),
+            alloc::string::String::from_utf8_lossy(&bytes).chars(),
+        );
+        itertools::assert_equal(Hello::LIST.chars_lossy(), "hello".chars());
+        assert_eq!(Empty::LIST.chars_lossy().next(), None);
+    }
+
+    #[test]
+    fn utf16() {
+        use utf16::{pop, Pop};
+
+        for c in (0..=char::MAX as u32).filter_map(char::from_u32) {
+            let mut units = [0; 2];
+            assert_eq!(pop(c.encode_utf16(&mut units)), Pop::Ok(c));
+        }
+        assert_eq!(pop(&[]), Pop::Empty);
+        assert_eq!(pop(&[0xD800]), Pop::Truncated);
+        assert_eq!(pop(&[0xD800, 0x0061]), Pop::Invalid);
+        assert_eq!(pop(&[0xDFFF, 0xD800]), Pop::Invalid);
+
+        type Wide = u16![0x0068, 0xD80C, 0xDC15, 0x0069];
+        itertools::assert_equal(Wide::LIST.chars(), "h𓀕i".chars());
+        assert!(Wide::LIST.is_utf16());
+        assert!(Wide::LIST.eq_str("h𓀕i"));
+        assert!(!Wide::LIST.eq_str("h𓀕"));
+        assert!(!Wide::LIST.eq_str("h𓀕ij"));
+        assert!(!Wide::LIST.eq_str("hxi"));
+
+        type Unpaired = u16![0x0068, 0xDC15, 0x0069];
+        itertools::assert_equal(Unpaired::LIST.chars(), "h".chars());
+        assert!(!Unpaired::LIST.is_utf16());
+        assert!(!Unpaired::LIST.eq_str("h"));
+        assert!(<u16![]>::LIST.eq_str(""));
+    }
+
+    const _: () = {
+        const UPPER: [u8; 5] = Hello::LIST.to_ascii_uppercase();
+        assert!(UPPER[0] == b'H' && UPPER[4] == b'O');
+        const LOWER: [char; 4] = <char!['A', 'b', '1', 'É']>::LIST.to_ascii_lowercase();
+        assert!(LOWER[0] == 'a' && LOWER[1] == 'b' && LOWER[2] == '1' && LOWER[3] == 'É');
+        assert!(Hello::LIST.is_ascii_alphabetic());
+        assert!(Hello::LIST.is_ascii_lowercase());
+        assert!(!Hello::LIST.is_ascii_uppercase());
+        assert!(Abcd::LIST.is_ascii_hexdigit());
+        assert!(!Abcd::LIST.is_ascii_digit());
+        assert!(<char!['1', '2']>::LIST.is_ascii_digit());
+        assert!(Empty::LIST.is_ascii_digit());
+        assert!(<u8![b' ', b'\t']>::LIST.is_ascii_whitespace());
+    };
+
+    const _: () = {
+        type Message = char!['h', 'é', '𓀕'];
+        assert!(Message::LIST.str_eq("hé𓀕"));
+        assert!(!Message::LIST.str_eq("hé"));
+        assert!(!Message::LIST.str_eq("hé𓀕!"));
+        assert!(!Message::LIST.str_eq("he𓀕"));
+        assert!(!Message::LIST.str_eq(""));
+        assert!(CharNil::LIST.str_eq(""));
+        assert!(!CharNil::LIST.str_eq("a"));
+    };
+
+    const _: () = {
+        assert!(Hello::LIST.eq_str("hello"));
+        assert!(!Hello::LIST.eq_str("hell"));
+        assert!(!Hello::LIST.eq_str("hello!"));
+        assert!(Empty::LIST.eq_str(""));
+        assert!(<u8![0xC3, 0xA9]>::LIST.eq_str("é"));
+    };
+
+    const _: () = {
+        type Bits = bool![false, true, true, false, false, false, false, true, true];
+        assert!(matches!(Bits::LIST.to_bits(), Some(0b0_1100_0011)));
+        assert!(matches!(
+            Bits::LIST.to_bits_lsb_first(),
+            Some(0b1_1000_0110)
+        ));
+        assert!(matches!(Bits::LIST.to_u16(), Some(0b0_1100_0011)));
+        assert!(Bits::LIST.to_u8().is_none());
+        assert!(matches!(BoolNil::LIST.to_bits(), Some(0)));
+        assert!(matches!(BoolNil::LIST.to_u8(), Some(0)));
+    };
+
+    const _: () = {
+        type Wide = u8![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+        assert!(matches!(
+            Wide::LIST.to_uint_be(),
+            Some(0x0102030405060708090A0B0C0D0E0F10)
+        ));
+        assert!(matches!(
+            Wide::LIST.to_uint_le(),
+            Some(0x100F0E0D0C0B0A090807060504030201)
+        ));
+        assert!(Wide::LIST.to_u64_be().is_none());
+        assert!(matches!(Empty::LIST.to_uint_be(), Some(0)));
+        assert!(matches!(Empty::LIST.to_uint_le(), Some(0)));
+        type TooWide = u8![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17];
+        assert!(TooWide::LIST.to_uint_be().is_none());
+        assert!(TooWide::LIST.to_uint_le().is_none());
+    };
+
+    const _: () = {
+        type Padded = char![' ', '\n', 'a', ' '];
+        assert!(Padded::LIST.trim_ascii_start().str_eq("a "));
+        assert!(Abcd::LIST.trim_ascii_start().str_eq("abcd"));
+        assert!(<char![' ', ' ']>::LIST.trim_ascii_start().is_empty());
+        assert!(CharNil::LIST.trim_ascii_start().is_empty());
+        assert!(Padded::LIST.skip_while_eq(' ').str_eq("\na "));
+        assert!(Abcd::LIST.skip_while_eq('b').str_eq("abcd"));
+        assert!(<char!['x', 'x']>::LIST.skip_while_eq('x').is_empty());
+    };
+
+    #[test]
+    fn split_whitespace() {
+        type Sentence = char![' ', 'a', '\u{3000}', 'b', 'c', ' ', '\n'];
+        let mut words = Sentence::LIST.split_whitespace();
+        itertools::assert_equal(words.next().unwrap(), &['a']);
+        itertools::assert_equal(words.next().unwrap(), &['b', 'c']);
+        assert!(words.next().is_none());
+        assert_eq!(Abcd::LIST.split_whitespace().count(), 1);
+        assert_eq!(<char![' ', ' ']>::LIST.split_whitespace().count(), 0);
+        assert_eq!(CharNil::LIST.split_whitespace().count(), 0);
+    }
+
+    const _: () = {
+        type Sigma = char!['Σ', 'σ', 'ς'];
+        assert!(Sigma::LIST.eq_ignore_case(&['σ', 'ς', 'Σ']));
+        assert!(Abcd::LIST.eq_ignore_case(&['A', 'b', 'C', 'd']));
+        assert!(!Abcd::LIST.eq_ignore_case(&['A', 'b', 'C']));
+        assert!(!Abcd::LIST.eq_ignore_case(&['A', 'b', 'C', 'd', 'e']));
+        assert!(CharNil::LIST.eq_ignore_case(&[]));
+        // kelvin sign, long s
+        assert!(<char!['\u{212A}', 'ſ']>::LIST.eq_ignore_case(&['k', 'S']));
+        // dotless and dotted i are distinct from ASCII i
+        assert!(!<char!['ı']>::LIST.eq_ignore_case(&['I']));
+        assert!(!<char!['İ']>::LIST.eq_ignore_case(&['i']));
+        // cherokee folds to uppercase
+        assert!(<char!['\u{13A0}']>::LIST.eq_ignore_case(&['\u{AB70}']));
+    };
+
+    #[test]
+    fn case_fold() {
+        for c in (0..=char::MAX as u32).filter_map(char::from_u32) {
+            let folded = case_fold::fold(c);
+            assert_eq!(case_fold::fold(folded), folded, "{:?}", c);
+        }
+        // `C` and `S` entries from `CaseFolding.txt`
+        for (c, folded) in [
+            ('A', 'a'),
+            ('\u{00B5}', 'μ'),
+            ('ſ', 's'),
+            ('\u{212A}', 'k'),
+            ('\u{212B}', 'å'),
+            ('ẞ', 'ß'),
+            ('ς', 'σ'),
+            ('ẛ', 'ṡ'),
+            ('\u{1FD3}', '\u{0390}'),
+            ('\u{FB05}', '\u{FB06}'),
+            ('\u{13F8}', '\u{13F0}'),
+            ('\u{1E900}', '\u{1E922}'),
+        ] {
+            assert_eq!(case_fold::fold(c), case_fold::fold(folded), "{:?}", c);
+        }
+        assert_eq!(case_fold::fold('ı'), 'ı');
+        assert_ne!(case_fold::fold('İ'), case_fold::fold('i'));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn case_fold_table() {
+        const TEMPLATE: &str = r##"
+// this file is @generated in typeslice's tests
+
+//! Unicode simple case folding, see [`List::eq_ignore_case`](crate::List::eq_ignore_case).
+
+/// Map `c` to a canonical member of its simple case folding equivalence class.
+///
+/// Two characters are equal ignoring case if they fold to the same character.
+pub(crate) const fn fold(c: char) -> char {
+    let mut lo = 0;
+    let mut hi = TABLE.len();
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        let (from, to) = TABLE[mid];
+        if from == c {
+            return to;
+        }
+        match (from as u32) < (c as u32) {
+            true => lo = mid + 1,
+            false => hi = mid,
+        }
+    }
+    c
 }
 
+/// The version of Unicode that [`TABLE`] was generated from.
 #[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::types::*;
-    use static_assertions::{const_assert, const_assert_eq};
+pub(crate) const UNICODE_VERSION: (u8, u8, u8) = ~version~;
 
-    type Empty = U8Nil;
-    type Hello = U8<b'h', U8<b'e', U8<b'l', U8<b'l', U8<b'o', U8Nil>>>>>;
+/// Characters that don't fold to themselves, sorted by the first element.
+///
+/// Derived from the one-to-one (simple) Unicode case mappings, as `lowercase(uppercase(c))`,
+/// which agrees with the `C` and `S` entries of `CaseFolding.txt`, except:
+/// - `ı` only folds to itself.
+/// - `ΐ`, `ΰ` and `ﬅ` fold to their equivalent precomposed characters.
+///
+/// Each character maps to a member of its equivalence class,
+/// which is not always the member chosen by `CaseFolding.txt`, e.g for Cherokee.
+#[rustfmt::skip]
+const TABLE: &[(char, char)] = &[
+"##;
 
-    const_assert!(Empty::LIST.slice_eq(b""));
-    const_assert_eq!(Empty::LEN, 0);
-    const_assert!(Hello::LIST.slice_eq(b"hello"));
-    const_assert_eq!(Hello::LEN, 5);
+        fn single(mut it: impl Iterator<Item = char>) -> Option<char> {
+            let c = it.next()?;
+            it.next().is_none().then_some(c)
+        }
+        fn fold(c: char) -> char {
+            match c {
+                'ı' => return c,
+                '\u{1FD3}' => return '\u{0390}',
+                '\u{1FE3}' => return '\u{03B0}',
+                '\u{FB05}' => return '\u{FB06}',
+                _ => {}
+            }
+            let upper = single(c.to_uppercase()).unwrap_or(c);
+            single(upper.to_lowercase()).unwrap_or(upper)
+        }
 
-    type Empty2 = u8![];
-    type Hello2 = u8![0x68, 0x65, 0x6c, 0x6c, 0x6f];
+        // The table follows the Unicode version of `std`, which changes between toolchains,
+        // so only characters with a mapping in both versions can be compared.
+        if char::UNICODE_VERSION != case_fold::UNICODE_VERSION {
+            let mut skipped = 0;
+            for c in (0..=char::MAX as u32).filter_map(char::from_u32) {
+                let (ours, theirs) = (case_fold::fold(c), fold(c));
+                match ours == c || theirs == c {
+                    true => skipped += usize::from(ours != theirs),
+                    false => assert_eq!(ours, theirs, "{:?}", c),
+                }
+            }
+            eprintln!(
+                "warning: case_fold.rs is for Unicode {:?}, but std is for Unicode {:?}. \
+                 Skipped comparing the whole file, and {} characters mapped in only one version",
+                case_fold::UNICODE_VERSION,
+                char::UNICODE_VERSION,
+                skipped,
+            );
+            return;
+        }
 
-    const_assert!(Empty2::LIST.slice_eq(b""));
-    const_assert_eq!(Empty2::LEN, 0);
-    const_assert!(Hello2::LIST.slice_eq(b"hello"));
-    const_assert_eq!(Hello2::LEN, 5);
+        let mut expected = TEMPLATE
+            .trim_start()
+            .replace("~version~", &format!("{:?}", char::UNICODE_VERSION));
+        for c in (0..=char::MAX as u32).filter_map(char::from_u32) {
+            let folded = fold(c);
+            if folded != c {
+                expected.push_str(&format!(
+                    "    ('\\u{{{:04X}}}', '\\u{{{:04X}}}'),\n",
+                    c as u32, folded as u32
+                ));
+            }
+        }
+        expected.push_str("];\n");
+
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/src/case_fold.rs");
+        match std::fs::read_to_string(path) {
+            Ok(actual) if expected == actual => return, // ok
+            _ => {}
+        }
+
+        let _ = std::fs::write(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/src/case_fold.rs.expected"),
+            expected,
+        );
+        panic!("generated file does not match")
+    }
+
+    const_assert!(matches!(Hello::LIST.get(0), Some(b'h')));
+    const_assert!(matches!(Hello::LIST.get(4), Some(b'o')));
+    const_assert!(Hello::LIST.get(5).is_none());
+    const_assert!(Hello::LIST.get(usize::MAX).is_none());
+    const_assert_eq!(Hello::LIST.skip(2).len(), 3);
+    const_assert!(List::from_slice(b"hello").slice_eq(b"hello"));
+    const_assert!(List::from_slice(b"hello").starts_with(b"he"));
+    const_assert!(matches!(List::from_slice(b"12").parse_u64(), Some(12)));
+    const_assert!(matches!(
+        List::from_slice(b"hello").find_subslice(b"ll"),
+        Some(2)
+    ));
+    const_assert_eq!(List::cons(&1, &List::cons(&2, &List::Empty)).len(), 2);
+    const_assert!(matches!(
+        List::cons(&1, &List::Empty),
+        List::Item(node) if *node.head() == 1 && node.rest().is_empty()
+    ));
 
     #[test]
-    fn test() {
-        itertools::assert_equal(Empty::LIST, b"");
-        itertools::assert_equal(Hello::LIST, b"hello");
-        itertools::assert_equal(Empty2::LIST, b"");
-        itertools::assert_equal(Hello2::LIST, b"hello");
+    fn to_array() {
+        assert_eq!(Hello::LIST.to_array(), *b"hello");
+        assert_eq!(Abcd::LIST.to_array(), ['a', 'b', 'c', 'd']);
+        assert_eq!(CharNil::LIST.to_array::<0>(), []);
+    }
+
+    #[test]
+    #[should_panic = "array length does not match list length"]
+    fn to_array_mismatch() {
+        let _ = Hello::LIST.to_array::<4>();
+    }
+
+    #[test]
+    fn pairwise() {
+        itertools::assert_equal(<i32![1, 4, 9]>::LIST.pairwise(), [(&1, &4), (&4, &9)]);
+        assert_eq!(<i32![1]>::LIST.pairwise().count(), 0);
+        assert_eq!(I32Nil::LIST.pairwise().count(), 0);
+    }
+
+    #[test]
+    fn split_by() {
+        let mut segments = <char!['a', ',', 'b', ',', 'c']>::LIST.split_by(',');
+        for expected in ['a', 'b', 'c'] {
+            let segment = segments.next().unwrap();
+            itertools::assert_equal(segment.iter().take_while(|it| **it != ','), [&expected]);
+        }
+        assert_eq!(segments.next(), None);
+
+        let segments = <char![',', ',']>::LIST.split_by(',');
+        itertools::assert_equal(segments.map(|it| it.len()), [2, 1, 0]);
+
+        assert_eq!(CharNil::LIST.split_by(',').count(), 1);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_vec() {
+        use alloc::vec::Vec;
+
+        assert_eq!(Hello::LIST.to_vec(), b"hello");
+        assert_eq!(Empty::LIST.to_vec(), b"");
+        assert_eq!(Vec::from(Abcd::LIST), ['a', 'b', 'c', 'd']);
+        let into: Vec<_> = Hello2::LIST.into();
+        assert_eq!(into, b"hello");
+
+        assert_eq!(*Hello::LIST.to_boxed_slice(), *b"hello");
+        assert!(Empty::LIST.to_boxed_slice().is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_string() {
+        use alloc::string::{String, ToString as _};
+
+        assert_eq!(String::from(Abcd::LIST), "abcd");
+        assert_eq!(String::from(CharNil::LIST), "");
+        assert_eq!(Abcd::LIST.to_string(), "abcd");
+        assert_eq!(Hello::LIST.to_utf8_string().unwrap(), "hello");
+        assert_eq!(Empty::LIST.to_utf8_string().unwrap(), "");
+        assert_eq!(
+            <u8![b'a', 0xC0]>::LIST
+                .to_utf8_string()
+                .unwrap_err()
+                .into_bytes(),
+            [b'a', 0xC0]
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn owned_list() {
+        let owned = b"hello".iter().copied().collect::<OwnedList<_>>();
+        assert_eq!(owned, Hello::LIST);
+        assert_eq!(Hello2::LIST, owned);
+        assert_ne!(owned, Empty::LIST);
+        assert_eq!(OwnedList::from(Hello::LIST), owned);
+        assert_eq!(owned.len(), 5);
+        assert_eq!(owned.get(1), Some(&b'e'));
+        assert_eq!(owned.with_list(|list| list.len()), 5);
+        assert!(owned.with_list(|list| list.slice_eq(b"hello")));
+        assert!(owned.with_list(|list| list == Hello::LIST));
+        assert!(OwnedList::<u8>::new().with_list(|list| list.is_empty()));
+        assert_eq!(owned.as_list(), b"hello");
+
+        let mut pushed = OwnedList::new();
+        pushed.push('a');
+        pushed.push('b');
+        assert!(pushed < Abcd::LIST.into());
+        itertools::assert_equal(&pushed, &['a', 'b']);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn join() {
+        assert_eq!(Hello::LIST.join("-"), "104-101-108-108-111");
+        assert_eq!(Abcd::LIST.join(""), "abcd");
+        assert_eq!(Abcd::LIST.join(&','), ['a', ',', 'b', ',', 'c', ',', 'd']);
+        assert_eq!(Hello::LIST.join(&b'.'), b"h.e.l.l.o");
+        assert_eq!(Empty::LIST.join(", "), "");
+        assert_eq!(Empty::LIST.join(&0), []);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn edit_distance() {
+        type Kitten = char!['k', 'i', 't', 't', 'e', 'n'];
+        assert_eq!(Kitten::LIST.edit_distance("sitting"), 3);
+        assert_eq!(Kitten::LIST.edit_distance("kitten"), 0);
+        assert_eq!(Kitten::LIST.edit_distance(""), 6);
+        assert_eq!(CharNil::LIST.edit_distance("abc"), 3);
+        assert_eq!(CharNil::LIST.edit_distance(""), 0);
+        assert_eq!(Abcd::LIST.edit_distance("bcd"), 1);
+        assert_eq!(Abcd::LIST.edit_distance("abcde"), 1);
+        assert_eq!(Abcd::LIST.edit_distance("ab𓀕d"), 1);
+    }
+
+    #[test]
+    fn split() {
+        type Path = char!['/', 'a', '/', 'b', 'c', '/', '/'];
+        let mut segments = Path::LIST.split(&'/');
+        for expected in ["", "a", "bc", "", ""] {
+            itertools::assert_equal(segments.next().unwrap().copied(), expected.chars());
+        }
+        assert!(segments.next().is_none());
+        assert_eq!(Abcd::LIST.split(&'/').map(|it| it.len()).sum::<usize>(), 4);
+        assert_eq!(CharNil::LIST.split(&'/').count(), 1);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn match_indices() {
+        assert_eq!(Overlapping::LIST.match_indices(&[1, 1]), [0, 1, 4]);
+        assert_eq!(Overlapping::LIST.match_indices(&[2, 1]), [3]);
+        assert_eq!(Overlapping::LIST.match_indices(&[2, 2]), []);
+        assert_eq!(Overlapping::LIST.match_indices(&[]), [0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(Hello::LIST.match_indices(b"l"), [2, 3]);
+        assert_eq!(Empty::LIST.match_indices(b"l"), []);
+    }
+
+    /// A fixed-capacity [`core::fmt::Write`]r, for testing formatting without `std`.
+    struct Buf {
+        bytes: [u8; 64],
+        len: usize,
+    }
+
+    impl Buf {
+        fn new() -> Self {
+            Self {
+                bytes: [0; 64],
+                len: 0,
+            }
+        }
+        fn as_str(&self) -> &str {
+            core::str::from_utf8(&self.bytes[..self.len]).unwrap()
+        }
+    }
+
+    impl core::fmt::Write for Buf {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            let dst = self
+                .bytes
+                .get_mut(self.len..self.len + s.len())
+                .ok_or(core::fmt::Error)?;
+            dst.copy_from_slice(s.as_bytes());
+            self.len += s.len();
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn fmt() {
+        use core::fmt::Write as _;
+
+        type Message = char!['h', 'i', '"', '👋'];
+
+        let mut buf = Buf::new();
+        write!(buf, "{}", Fmt(Message::LIST)).unwrap();
+        assert_eq!(buf.as_str(), "hi\"👋");
+
+        let mut buf = Buf::new();
+        write!(buf, "{:?}", Fmt(Message::LIST)).unwrap();
+        assert_eq!(buf.as_str(), "\"hi\\\"👋\"");
+
+        let mut buf = Buf::new();
+        write!(buf, "[{}]", Fmt(CharNil::LIST)).unwrap();
+        assert_eq!(buf.as_str(), "[]");
+
+        let mut buf = Buf::new();
+        write!(buf, "{}", Message::LIST).unwrap();
+        assert_eq!(buf.as_str(), "hi\"👋");
+
+        // padding and truncation count chars, like for a str
+        let mut buf = Buf::new();
+        write!(buf, "{:>6}|{:<6}|", Message::LIST, Message::LIST).unwrap();
+        assert_eq!(buf.as_str(), "  hi\"👋|hi\"👋  |");
+
+        let mut buf = Buf::new();
+        write!(buf, "{:-^7}|{:2}", Message::LIST, Message::LIST).unwrap();
+        assert_eq!(buf.as_str(), "-hi\"👋--|hi\"👋");
+
+        let mut buf = Buf::new();
+        write!(buf, "{:.2}|{:>4.1}", Message::LIST, Fmt(Message::LIST)).unwrap();
+        assert_eq!(buf.as_str(), "hi|   h");
+    }
+
+    #[test]
+    fn display_with() {
+        use core::fmt::Write as _;
+
+        type Numbers = u8![1, 20, 3];
+
+        for (args, expected) in [
+            (
+                format_args!("{}", Numbers::LIST.display_with(", ")),
+                "1, 20, 3",
+            ),
+            (format_args!("{}", Numbers::LIST.display_with("")), "1203"),
+            (
+                format_args!("{:>10}", Numbers::LIST.display_with(", ")),
+                "  1, 20, 3",
+            ),
+            (
+                format_args!("{:10}", Numbers::LIST.display_with(", ")),
+                "1, 20, 3  ",
+            ),
+            (
+                format_args!("{:*^11}", Numbers::LIST.display_with(", ")),
+                "*1, 20, 3**",
+            ),
+            (
+                format_args!("{:2}", Numbers::LIST.display_with(", ")),
+                "1, 20, 3",
+            ),
+            (format_args!("[{}]", Empty::LIST.display_with(", ")), "[]"),
+            (format_args!("{:.1}", Abcd::LIST.display_with("")), "abcd"),
+        ] {
+            let mut buf = Buf::new();
+            buf.write_fmt(args).unwrap();
+            assert_eq!(buf.as_str(), expected);
+        }
+    }
+
+    #[test]
+    fn concat_fmt() {
+        use core::fmt::Write as _;
+
+        type Signed = i16![-1, 0x10];
+        type Bits = u8![5, 255];
+        type Octets = u8![8, 255];
+        type Wide = u16![1];
+
+        for (args, expected) in [
+            (format_args!("{:x}", Hello::LIST), "68656c6c6f"),
+            (format_args!("{:X}", Sixteens::LIST), "1010"),
+            (format_args!("{:#x}", Hello::LIST), "0x68656c6c6f"),
+            (format_args!("{:b}", Bits::LIST), "0000010111111111"),
+            (format_args!("{:#o}", Octets::LIST), "0o010377"),
+            (format_args!("{:x}", Signed::LIST), "ffff0010"),
+            (format_args!("{:o}", Wide::LIST), "000001"),
+            (format_args!("[{:x}]", Empty::LIST), "[]"),
+        ] {
+            let mut buf = Buf::new();
+            buf.write_fmt(args).unwrap();
+            assert_eq!(buf.as_str(), expected);
+        }
+    }
+
+    #[test]
+    fn debug() {
+        use core::fmt::Write as _;
+
+        type Numbers = u8![1, 2];
+
+        let mut buf = Buf::new();
+        write!(buf, "{:?}", Numbers::LIST).unwrap();
+        assert_eq!(buf.as_str(), "[1, 2]");
+
+        let mut buf = Buf::new();
+        write!(buf, "{:?}", Empty::LIST).unwrap();
+        assert_eq!(buf.as_str(), "[]");
+
+        let mut buf = Buf::new();
+        write!(buf, "{:?}", Abcd::LIST).unwrap();
+        assert_eq!(buf.as_str(), "['a', 'b', 'c', 'd']");
+
+        let mut buf = Buf::new();
+        write!(buf, "{:#?}", Empty::LIST).unwrap();
+        assert_eq!(buf.as_str(), "Empty");
+
+        let mut buf = Buf::new();
+        write!(buf, "{:#?}", <u8![1]>::LIST).unwrap();
+        assert_eq!(
+            buf.as_str(),
+            "Item {\n    head: 1,\n    rest: Empty,\n    len: 1,\n}"
+        );
+
+        let mut buf = Buf::new();
+        write!(buf, "{:?}", List::from_slice(&[1, 2])).unwrap();
+        assert_eq!(buf.as_str(), "[1, 2]");
+
+        let mut buf = Buf::new();
+        write!(buf, "{:#?}", List::from_slice(&[1])).unwrap();
+        assert_eq!(buf.as_str(), "Slice(\n    [\n        1,\n    ],\n)");
+    }
+
+    #[test]
+    fn ord() {
+        type Hell = u8![b'h', b'e', b'l', b'l'];
+        type Help = u8![b'h', b'e', b'l', b'p'];
+
+        for (left, right, left_slice, right_slice) in [
+            (Empty::LIST, Hello::LIST, &b""[..], &b"hello"[..]),
+            (Hell::LIST, Hello::LIST, b"hell", b"hello"),
+            (Hello::LIST, Help::LIST, b"hello", b"help"),
+            (Empty::LIST, Empty2::LIST, b"", b""),
+            (Hello::LIST, Hello2::LIST, b"hello", b"hello"),
+        ] {
+            let expected = left_slice.cmp(right_slice);
+            assert_eq!(left.cmp(&right), expected);
+            assert_eq!(left.partial_cmp(&right), Some(expected));
+            assert_eq!(right.cmp(&left), expected.reverse());
+        }
+    }
+
+    #[test]
+    fn radix() {
+        use core::fmt::Write as _;
+
+        for (radix, expected) in [
+            (16, "ff 10"),
+            (10, "255 16"),
+            (8, "377 20"),
+            (2, "11111111 10000"),
+        ] {
+            let mut buf = Buf::new();
+            let list = RadixList::new(<u8![255, 16]>::LIST, radix).unwrap();
+            write!(buf, "{}", list).unwrap();
+            assert_eq!(buf.as_str(), expected);
+        }
+
+        let mut buf = Buf::new();
+        write!(
+            buf,
+            "{}",
+            RadixList::new(
+                <i128![-170141183460469231731687303715884105728, 0]>::LIST,
+                36
+            )
+            .unwrap()
+        )
+        .unwrap();
+        assert_eq!(buf.as_str(), "-7ksyyizzkutudzbv8aqztecjk 0");
+
+        let mut buf = Buf::new();
+        write!(buf, "[{}]", RadixList::new(Empty::LIST, 16).unwrap()).unwrap();
+        assert_eq!(buf.as_str(), "[]");
+    }
+
+    const_assert!(RadixList::new(Hello::LIST, 0).is_none());
+    const_assert!(RadixList::new(Hello::LIST, 1).is_none());
+    const_assert!(RadixList::new(Hello::LIST, 37).is_none());
+    const_assert!(matches!(RadixList::new(Hello::LIST, 2), Some(it) if it.radix() == 2));
+    const_assert!(matches!(RadixList::new(Hello::LIST, 36), Some(it) if it.list().len() == 5));
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash() {
+        fn hash_of<T: std::hash::Hash + ?Sized>(it: &T) -> u64 {
+            use std::hash::{BuildHasher as _, BuildHasherDefault};
+            BuildHasherDefault::<std::collections::hash_map::DefaultHasher>::default().hash_one(it)
+        }
+        for (list, slice) in [
+            (Hello::LIST, &b"hello"[..]),
+            (Hello2::LIST, b"hello"),
+            (Empty::LIST, b""),
+        ] {
+            assert_eq!(hash_of(&list), hash_of(slice));
+        }
+        assert_eq!(hash_of(&Abcd::LIST), hash_of(&['a', 'b', 'c', 'd'][..]));
+        assert_ne!(hash_of(&Hello::LIST), hash_of(&b"hell"[..]));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash_writes() {
+        /// Records each call to [`Hasher::write`](std::hash::Hasher::write).
+        #[derive(Default)]
+        struct Writes(Vec<Vec<u8>>);
+        impl std::hash::Hasher for Writes {
+            fn finish(&self) -> u64 {
+                0
+            }
+            fn write(&mut self, bytes: &[u8]) {
+                self.0.push(bytes.to_vec())
+            }
+        }
+        fn writes<T: std::hash::Hash + ?Sized>(it: &T) -> Vec<Vec<u8>> {
+            let mut hasher = Writes::default();
+            it.hash(&mut hasher);
+            hasher.0
+        }
+
+        // slice-backed lists make the same calls as slices
+        assert_eq!(writes(&List::from_slice(b"hello")), writes(&b"hello"[..]));
+        assert_eq!(writes(&List::from_slice(&[1, -2])), writes(&[1, -2][..]));
+        assert_eq!(writes(&List::<u8>::from_slice(&[])), writes(&b""[..]));
+
+        // cons lists write the same stream of bytes
+        for (list, slice) in [(Hello::LIST, &b"hello"[..]), (Empty::LIST, b"")] {
+            assert_eq!(writes(&list).concat(), writes(slice).concat());
+        }
+        let tail = List::from_slice(b"llo");
+        let tail = List::cons(&b'e', &tail);
+        let list = List::cons(&b'h', &tail);
+        assert_eq!(writes(&list).concat(), writes(&b"hello"[..]).concat());
     }
 
     #[cfg(feature = "std")]
@@ -0,0 +1,112 @@
+//! Type-level operations on [`TypeSlice`]s.
+//!
+//! Each operation is a trait whose [`Output`](Take::Output) is another [`TypeSlice`]
+//! of the same element type.
+//! ```
+//! use static_assertions::assert_type_eq_all;
+//! use typeslice::ops::{Skip, Take};
+//!
+//! type Abcd = typeslice::char!['a', 'b', 'c', 'd'];
+//! assert_type_eq_all!(<Abcd as Take<2>>::Output, typeslice::char!['a', 'b']);
+//! assert_type_eq_all!(<Abcd as Skip<2>>::Output, typeslice::char!['c', 'd']);
+//! ```
+//!
+//! Stable Rust can't do arithmetic on const generics,
+//! so counts are implemented for `0..=`[`MAX_COUNT`] only.
+//! A larger count on a non-empty slice fails to compile,
+//! because the trait is not implemented:
+//! ```compile_fail
+//! use typeslice::ops::Take;
+//! let _: Option<<typeslice::u8![1] as Take<65>>::Output> = None;
+//! ```
+//! ```text
+//! error[E0277]: the trait bound `U8<1, U8Nil>: typeslice::ops::Take<65>` is not satisfied
+//! ```
+
+use crate::{types, TypeSlice};
+
+/// The largest count accepted by [`Take`] and [`Skip`] on a non-empty [`TypeSlice`].
+///
+/// Empty slices accept any count.
+pub const MAX_COUNT: usize = 64;
+
+/// The first `N` elements of a [`TypeSlice`].
+///
+/// If `N` exceeds the length, the [`Output`](Take::Output) is the whole slice.
+pub trait Take<const N: usize> {
+    /// A [`TypeSlice`] with (at most) `N` elements.
+    type Output;
+}
+
+/// A [`TypeSlice`] without its first `N` elements.
+///
+/// If `N` exceeds the length, the [`Output`](Skip::Output) is empty.
+pub trait Skip<const N: usize> {
+    /// A [`TypeSlice`] with the remaining elements.
+    type Output;
+}
+
+/// Append another [`TypeSlice`] of the same element type.
+///
+/// ```
+/// use static_assertions::assert_type_eq_all;
+/// use typeslice::ops::Concat;
+///
+/// type Abcd = <typeslice::char!['a', 'b'] as Concat<typeslice::char!['c', 'd']>>::Output;
+/// assert_type_eq_all!(Abcd, typeslice::char!['a', 'b', 'c', 'd']);
+/// ```
+pub trait Concat<Other> {
+    /// A [`TypeSlice`] with the elements of `Self` followed by the elements of `Other`.
+    type Output;
+}
+
+macro_rules! impl_concat {
+    ($name:ident/$nil:ident for $ty:ty = $default:expr) => {
+        impl<Other: TypeSlice<$ty>> Concat<Other> for types::$nil {
+            type Output = Other;
+        }
+        impl<const ELEM: $ty, Rest: Concat<Other>, Other> Concat<Other>
+            for types::$name<ELEM, Rest>
+        {
+            type Output = types::$name<ELEM, Rest::Output>;
+        }
+    };
+}
+
+for_all_const_types!(impl_concat);
+
+macro_rules! impl_take_skip {
+    ($name:ident/$nil:ident for $ty:ty = $default:expr) => {
+        impl<const N: usize> Take<N> for types::$nil {
+            type Output = Self;
+        }
+        impl<const N: usize> Skip<N> for types::$nil {
+            type Output = Self;
+        }
+        impl<const ELEM: $ty, Rest> Take<0> for types::$name<ELEM, Rest> {
+            type Output = types::$nil;
+        }
+        impl<const ELEM: $ty, Rest> Skip<0> for types::$name<ELEM, Rest> {
+            type Output = Self;
+        }
+        impl_take_skip!(@succ $name/$nil for $ty;
+            1 0, 2 1, 3 2, 4 3, 5 4, 6 5, 7 6, 8 7, 9 8, 10 9, 11 10, 12 11, 13 12, 14 13, 15 14, 16 15, 17
+            16, 18 17, 19 18, 20 19, 21 20, 22 21, 23 22, 24 23, 25 24, 26 25, 27 26, 28 27, 29 28, 30 29,
+            31 30, 32 31, 33 32, 34 33, 35 34, 36 35, 37 36, 38 37, 39 38, 40 39, 41 40, 42 41, 43 42, 44
+            43, 45 44, 46 45, 47 46, 48 47, 49 48, 50 49, 51 50, 52 51, 53 52, 54 53, 55 54, 56 55, 57 56,
+            58 57, 59 58, 60 59, 61 60, 62 61, 63 62, 64 63
+        );
+    };
+    (@succ $name:ident/$nil:ident for $ty:ty; $($n:literal $pred:literal),* $(,)?) => {
+        $(
+            impl<const ELEM: $ty, Rest: Take<$pred>> Take<$n> for types::$name<ELEM, Rest> {
+                type Output = types::$name<ELEM, Rest::Output>;
+            }
+            impl<const ELEM: $ty, Rest: Skip<$pred>> Skip<$n> for types::$name<ELEM, Rest> {
+                type Output = Rest::Output;
+            }
+        )*
+    };
+}
+
+for_all_const_types!(impl_take_skip);
@@ -0,0 +1,106 @@
+//! `const` UTF-16 decoding, for working with [`List`](crate::List)s of code units at compile time.
+//!
+//! ```
+//! use typeslice::utf16::{pop, Pop};
+//! const FIRST: Pop = pop(&[0xD80C, 0xDC15, 0x0020]);
+//! assert!(matches!(FIRST, Pop::Ok('𓀕')));
+//! ```
+
+use crate::List;
+
+pub use crate::utf8::Pop;
+
+/// Decode the first [`prim@char`] in `units`.
+///
+/// A lone surrogate is [`Pop::Invalid`],
+/// unless it is a leading surrogate at the end of `units`, which is [`Pop::Truncated`].
+/// ```
+/// use typeslice::utf16::{pop, Pop};
+/// assert_eq!(pop(&[0x0061]), Pop::Ok('a'));
+/// assert_eq!(pop(&[]), Pop::Empty);
+/// assert_eq!(pop(&[0xD80C]), Pop::Truncated);
+/// assert_eq!(pop(&[0xDC15]), Pop::Invalid);
+/// ```
+pub const fn pop(units: &[u16]) -> Pop {
+    let Some((lead, units)) = units.split_first() else {
+        return Pop::Empty;
+    };
+    let code = match *lead {
+        0xD800..=0xDBFF => match units.split_first() {
+            Some((trail @ 0xDC00..=0xDFFF, _)) => {
+                0x10000 + ((*lead as u32 - 0xD800) << 10) + (*trail as u32 - 0xDC00)
+            }
+            Some(_) => return Pop::Invalid,
+            None => return Pop::Truncated,
+        },
+        0xDC00..=0xDFFF => return Pop::Invalid,
+        lead => lead as u32,
+    };
+    match char::from_u32(code) {
+        Some(c) => Pop::Ok(c),
+        None => Pop::Invalid,
+    }
+}
+
+/// Iterator over the [`prim@char`]s decoded from a [`List`] of UTF-16 code units.
+/// See [`List::chars`].
+///
+/// Iteration stops at the end of the list, or at the first sequence that can't be decoded.
+/// [`Chars::pop`] tells them apart in `const` contexts.
+pub struct Chars<'a> {
+    inner: List<'a, u16>,
+}
+
+impl<'a> Chars<'a> {
+    pub(crate) const fn new(inner: List<'a, u16>) -> Self {
+        Self { inner }
+    }
+    /// Decode the next [`prim@char`], returning it and the remaining code units if it was [`Pop::Ok`].
+    /// If not, the returned iterator is unchanged.
+    pub const fn pop(&self) -> (Pop, Self) {
+        // enough for any UTF-16 sequence
+        let (buf, len) = self.inner.copy_prefix::<2>(0);
+        match pop(buf.split_at(len).0) {
+            Pop::Ok(c) => (
+                Pop::Ok(c),
+                Self {
+                    inner: self.inner.skip(c.len_utf16()),
+                },
+            ),
+            other => (other, *self),
+        }
+    }
+    /// Return the code units that haven't been decoded.
+    pub const fn as_list(&self) -> List<'a, u16> {
+        self.inner
+    }
+}
+
+impl Clone for Chars<'_> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl Copy for Chars<'_> {}
+
+impl core::fmt::Debug for Chars<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Chars").field(&self.inner).finish()
+    }
+}
+
+impl Iterator for Chars<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.pop() {
+            (Pop::Ok(c), rest) => {
+                *self = rest;
+                Some(c)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl core::iter::FusedIterator for Chars<'_> {}
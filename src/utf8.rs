@@ -1,3 +1,13 @@
+//! `const` UTF-8 decoding, for working with [`List`](crate::List)s of bytes at compile time.
+//!
+//! ```
+//! use typeslice::utf8::{pop, Pop};
+//! const FIRST: Pop = pop("𓀕 and more".as_bytes());
+//! assert!(matches!(FIRST, Pop::Ok('𓀕')));
+//! ```
+
+use crate::List;
+
 // https://github.com/rust-lang/rust/blob/6db96de66c2c0ea3f4f2f348ed1a83c2c507687d/library/core/src/str/validations.rs
 
 macro_rules! next_in_slice {
@@ -12,50 +22,264 @@ macro_rules! next_in_slice {
     };
 }
 
+/// The result of decoding a [`prim@char`] from the start of some code units,
+/// like UTF-8 bytes or UTF-16 code units.
+/// See [`pop`] and [`utf16::pop`](crate::utf16::pop).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Pop {
+    /// There were no code units.
     Empty,
+    /// The code units ended partway through an encoded [`prim@char`].
     Truncated,
+    /// The code units did not start with a valid encoding of a [`prim@char`].
     Invalid,
+    /// A [`prim@char`] was decoded.
+    ///
+    /// Its length in code units is [`char::len_utf8`] for UTF-8,
+    /// or [`char::len_utf16`] for UTF-16.
     Ok(char),
 }
 
-pub const fn pop(mut bytes: &[u8]) -> Pop {
+/// Decode the first [`prim@char`] in `bytes`.
+///
+/// ```
+/// use typeslice::utf8::{pop, Pop};
+/// assert_eq!(pop(b"a"), Pop::Ok('a'));
+/// assert_eq!(pop(b""), Pop::Empty);
+/// assert_eq!(pop(&[0xE2, 0x82]), Pop::Truncated);
+/// assert_eq!(pop(&[0xED, 0xA0, 0x80]), Pop::Invalid); // a surrogate
+/// assert_eq!(pop(&[0xC0, 0x80]), Pop::Invalid); // an overlong encoding
+/// ```
+pub const fn pop(bytes: &[u8]) -> Pop {
+    pop_with_len(bytes).0
+}
+
+/// Decode the first [`prim@char`] in `bytes`, replacing invalid or truncated sequences with
+/// [`char::REPLACEMENT_CHARACTER`].
+///
+/// Returns [`None`] if `bytes` is empty, else the [`prim@char`] and the number of bytes it replaces.
+/// Like [`String::from_utf8_lossy`](https://doc.rust-lang.org/std/string/struct.String.html#method.from_utf8_lossy),
+/// each maximal prefix of a valid sequence is replaced by one [`char::REPLACEMENT_CHARACTER`].
+/// ```
+/// use typeslice::utf8::pop_lossy;
+/// assert_eq!(pop_lossy("𓀕".as_bytes()), Some(('𓀕', 4)));
+/// assert_eq!(pop_lossy(&[0xF0, 0x93, b'a']), Some((char::REPLACEMENT_CHARACTER, 2)));
+/// assert_eq!(pop_lossy(&[0xFF, b'a']), Some((char::REPLACEMENT_CHARACTER, 1)));
+/// assert_eq!(pop_lossy(b""), None);
+/// ```
+pub const fn pop_lossy(bytes: &[u8]) -> Option<(char, usize)> {
+    match pop_with_len(bytes) {
+        (Pop::Ok(c), len) => Some((c, len)),
+        (Pop::Empty, _) => None,
+        (Pop::Truncated | Pop::Invalid, len) => Some((char::REPLACEMENT_CHARACTER, len)),
+    }
+}
+
+/// Decode the first [`prim@char`] in `bytes`, also returning the number of bytes in the sequence,
+/// or in its longest valid prefix if it is invalid or truncated.
+const fn pop_with_len(mut bytes: &[u8]) -> (Pop, usize) {
     let Some(x) = next_in_slice!(bytes) else {
-        return Pop::Empty;
+        return (Pop::Empty, 0);
     };
-    if x < 128 {
-        return match char::from_u32(x as u32) {
-            Some(c) => Pop::Ok(c),
-            None => Pop::Invalid,
-        };
+    // the width of the sequence, and the range of the second byte, which rules out
+    // overlong encodings, surrogates and values above char::MAX
+    let (width, lo, hi) = match x {
+        0x00..=0x7F => return (Pop::Ok(x as char), 1),
+        0xC2..=0xDF => (2, 0x80, 0xBF),
+        0xE0 => (3, 0xA0, 0xBF),
+        0xE1..=0xEC | 0xEE..=0xEF => (3, 0x80, 0xBF),
+        0xED => (3, 0x80, 0x9F),
+        0xF0 => (4, 0x90, 0xBF),
+        0xF1..=0xF3 => (4, 0x80, 0xBF),
+        0xF4 => (4, 0x80, 0x8F),
+        _ => return (Pop::Invalid, 1),
     };
-
-    let init = utf8_first_byte(x, 2);
-    let Some(y) = next_in_slice!(bytes) else {
-        return Pop::Truncated;
-    };
-    let mut ch = utf8_acc_cont_byte(init, y);
-    if x >= 0xE0 {
-        let Some(z) = next_in_slice!(bytes) else {
-            return Pop::Truncated;
+    let mut ch = utf8_first_byte(x, width);
+    let mut ix = 1;
+    while ix < width {
+        let Some(y) = next_in_slice!(bytes) else {
+            return (Pop::Truncated, ix as usize);
         };
-        let y_z = utf8_acc_cont_byte((y & CONT_MASK) as u32, z);
-        ch = init << 12 | y_z;
-        if x >= 0xF0 {
-            let Some(w) = next_in_slice!(bytes) else {
-                return Pop::Truncated;
-            };
-            ch = (init & 7) << 18 | utf8_acc_cont_byte(y_z, w)
+        let (lo, hi) = match ix {
+            1 => (lo, hi),
+            _ => (0x80, 0xBF),
+        };
+        if y < lo || y > hi {
+            return (Pop::Invalid, ix as usize);
         }
+        ch = utf8_acc_cont_byte(ch, y);
+        ix += 1;
     }
 
     let _ = bytes; // done
     match char::from_u32(ch) {
-        Some(c) => Pop::Ok(c),
-        None => Pop::Invalid,
+        Some(c) => (Pop::Ok(c), width as usize),
+        None => (Pop::Invalid, width as usize),
+    }
+}
+
+/// Decode the last [`prim@char`] in `bytes`.
+///
+/// ```
+/// use typeslice::utf8::{pop_back, Pop};
+/// assert_eq!(pop_back("tag𓀕".as_bytes()), Pop::Ok('𓀕'));
+/// assert_eq!(pop_back(b""), Pop::Empty);
+/// assert_eq!(pop_back(&[b'a', 0xE2, 0x82]), Pop::Truncated);
+/// assert_eq!(pop_back(&[0x82]), Pop::Invalid);
+/// ```
+pub const fn pop_back(bytes: &[u8]) -> Pop {
+    let Some(mut start) = bytes.len().checked_sub(1) else {
+        return Pop::Empty;
+    };
+    while start > 0 && bytes.len() - start < 4 && is_cont_byte(bytes[start]) {
+        start -= 1;
+    }
+    if is_cont_byte(bytes[start]) {
+        return Pop::Invalid;
+    }
+    let (_, tail) = bytes.split_at(start);
+    match pop(tail) {
+        Pop::Ok(c) if c.len_utf8() == tail.len() => Pop::Ok(c),
+        // the last sequence is followed by stray continuation bytes
+        Pop::Ok(_) => Pop::Invalid,
+        other => other,
+    }
+}
+
+/// Iterator over the [`prim@char`]s decoded from a [`List`] of bytes.
+/// See [`List::chars`].
+///
+/// Iteration stops at the end of the list, or at the first sequence that can't be decoded.
+/// [`Chars::pop`] tells them apart in `const` contexts.
+pub struct Chars<'a> {
+    inner: List<'a, u8>,
+}
+
+impl<'a> Chars<'a> {
+    pub(crate) const fn new(inner: List<'a, u8>) -> Self {
+        Self { inner }
+    }
+    /// Decode the next [`prim@char`], returning it and the remaining bytes if it was [`Pop::Ok`].
+    /// If not, the returned iterator is unchanged.
+    ///
+    /// ```
+    /// # use typeslice::TypeSlice as _;
+    /// use typeslice::utf8::Pop;
+    /// type Message = typeslice::utf8!("𓀕!");
+    /// const FIRST: (Pop, typeslice::utf8::Chars) = Message::LIST.chars().pop();
+    /// assert_eq!(FIRST.0, Pop::Ok('𓀕'));
+    /// assert_eq!(FIRST.1.pop().0, Pop::Ok('!'));
+    /// ```
+    pub const fn pop(&self) -> (Pop, Self) {
+        // enough for any UTF-8 sequence
+        let (buf, len) = self.inner.copy_prefix::<4>(0);
+        match pop(buf.split_at(len).0) {
+            Pop::Ok(c) => (
+                Pop::Ok(c),
+                Self {
+                    inner: self.inner.skip(c.len_utf8()),
+                },
+            ),
+            other => (other, *self),
+        }
+    }
+    /// Return the bytes that haven't been decoded.
+    pub const fn as_list(&self) -> List<'a, u8> {
+        self.inner
+    }
+}
+
+impl Clone for Chars<'_> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl Copy for Chars<'_> {}
+
+impl core::fmt::Debug for Chars<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Chars").field(&self.inner).finish()
     }
 }
 
+impl Iterator for Chars<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.pop() {
+            (Pop::Ok(c), rest) => {
+                *self = rest;
+                Some(c)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl core::iter::FusedIterator for Chars<'_> {}
+
+/// Iterator over the [`prim@char`]s decoded from a [`List`] of bytes,
+/// with invalid sequences replaced by [`char::REPLACEMENT_CHARACTER`].
+/// See [`List::chars_lossy`] and [`pop_lossy`].
+pub struct CharsLossy<'a> {
+    inner: List<'a, u8>,
+}
+
+impl<'a> CharsLossy<'a> {
+    pub(crate) const fn new(inner: List<'a, u8>) -> Self {
+        Self { inner }
+    }
+    /// Decode the next [`prim@char`], returning it and the remaining bytes,
+    /// or [`None`] if there are no more bytes.
+    pub const fn pop(&self) -> Option<(char, Self)> {
+        // enough for any UTF-8 sequence
+        let (buf, len) = self.inner.copy_prefix::<4>(0);
+        match pop_lossy(buf.split_at(len).0) {
+            Some((c, len)) => Some((
+                c,
+                Self {
+                    inner: self.inner.skip(len),
+                },
+            )),
+            None => None,
+        }
+    }
+    /// Return the bytes that haven't been decoded.
+    pub const fn as_list(&self) -> List<'a, u8> {
+        self.inner
+    }
+}
+
+impl Clone for CharsLossy<'_> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl Copy for CharsLossy<'_> {}
+
+impl core::fmt::Debug for CharsLossy<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("CharsLossy").field(&self.inner).finish()
+    }
+}
+
+impl Iterator for CharsLossy<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (c, rest) = self.pop()?;
+        *self = rest;
+        Some(c)
+    }
+}
+
+impl core::iter::FusedIterator for CharsLossy<'_> {}
+
+/// Returns true if `byte` is a continuation byte, i.e `0b10xx_xxxx`.
+const fn is_cont_byte(byte: u8) -> bool {
+    byte & !CONT_MASK == TAG_CONT
+}
+
 /// Mask of the value bits of a continuation byte.
 const CONT_MASK: u8 = 0b0011_1111;
 
@@ -72,3 +296,45 @@ const fn utf8_first_byte(byte: u8, width: u32) -> u32 {
 const fn utf8_acc_cont_byte(ch: u32, byte: u8) -> u32 {
     (ch << 6) | (byte & CONT_MASK) as u32
 }
+
+/// Encode `c` as UTF-8, returning the bytes and how many of them are used.
+///
+/// This is the inverse of [`pop`].
+/// ```
+/// use typeslice::utf8::{encode_utf8, pop, Pop};
+/// const ENCODED: ([u8; 4], usize) = encode_utf8('€');
+/// assert_eq!(ENCODED, ([0xE2, 0x82, 0xAC, 0], 3));
+/// assert_eq!(pop(&ENCODED.0), Pop::Ok('€'));
+/// ```
+pub const fn encode_utf8(c: char) -> ([u8; 4], usize) {
+    let code = c as u32;
+    let len = c.len_utf8();
+    let bytes = match len {
+        1 => [code as u8, 0, 0, 0],
+        2 => [
+            (code >> 6 & 0x1F) as u8 | TAG_TWO_B,
+            (code & 0x3F) as u8 | TAG_CONT,
+            0,
+            0,
+        ],
+        3 => [
+            (code >> 12 & 0x0F) as u8 | TAG_THREE_B,
+            (code >> 6 & 0x3F) as u8 | TAG_CONT,
+            (code & 0x3F) as u8 | TAG_CONT,
+            0,
+        ],
+        _ => [
+            (code >> 18 & 0x07) as u8 | TAG_FOUR_B,
+            (code >> 12 & 0x3F) as u8 | TAG_CONT,
+            (code >> 6 & 0x3F) as u8 | TAG_CONT,
+            (code & 0x3F) as u8 | TAG_CONT,
+        ],
+    };
+    (bytes, len)
+}
+
+// https://github.com/rust-lang/rust/blob/6db96de66c2c0ea3f4f2f348ed1a83c2c507687d/library/core/src/char/methods.rs
+const TAG_CONT: u8 = 0b1000_0000;
+const TAG_TWO_B: u8 = 0b1100_0000;
+const TAG_THREE_B: u8 = 0b1110_0000;
+const TAG_FOUR_B: u8 = 0b1111_0000;
@@ -1,7 +1,8 @@
 #![allow(dead_code)]
 #![cfg(feature = "macros")]
 
-use static_assertions::assert_type_eq_all;
+use static_assertions::{assert_type_eq_all, const_assert};
+use typeslice::TypeSlice;
 
 type Empty = typeslice::from_str!();
 type Empty2 = typeslice::from_str!("");
@@ -16,3 +17,127 @@ type BEmpty = typeslice::from_bytes!();
 type BEmpty2 = typeslice::from_bytes!(b"");
 type BEmpty3 = typeslice::u8![];
 assert_type_eq_all!(BEmpty, BEmpty2, BEmpty3);
+
+type BArray = typeslice::from_bytes!([0x68, b'e', 108u8, 108, 0o157]);
+type BArray2 = typeslice::from_bytes!(b"hello");
+assert_type_eq_all!(BArray, BArray2);
+
+type BArrayEmpty = typeslice::from_bytes!([]);
+assert_type_eq_all!(BArrayEmpty, BEmpty);
+
+type Words = typeslice::from_words!("  source destination\ttarget  ");
+type Words2 = typeslice::types::Group<
+    typeslice::from_str!("source"),
+    typeslice::types::Group<
+        typeslice::from_str!("destination"),
+        typeslice::types::Group<typeslice::from_str!("target"), typeslice::types::GroupNil>,
+    >,
+>;
+assert_type_eq_all!(Words, Words2);
+
+type WEmpty = typeslice::from_words!();
+type WEmpty2 = typeslice::from_words!("   ");
+assert_type_eq_all!(WEmpty, WEmpty2, typeslice::types::GroupNil);
+
+#[test]
+fn words() {
+    use typeslice::TypeGroup;
+
+    let words = Words::LISTS
+        .into_iter()
+        .copied()
+        .map(String::from_iter)
+        .collect::<Vec<_>>();
+    assert_eq!(words, ["source", "destination", "target"]);
+    assert_eq!(<Words as TypeGroup<char>>::LEN, 3);
+}
+
+type Existing = typeslice::u8![1, 2, 3];
+assert_type_eq_all!(
+    typeslice::push_back!(Existing, 4u8),
+    typeslice::u8![1, 2, 3, 4]
+);
+assert_type_eq_all!(
+    typeslice::push_back!(typeslice::u8![1, 2, 3], b'a'),
+    typeslice::u8![1, 2, 3, 97]
+);
+assert_type_eq_all!(typeslice::push_back!(BEmpty, 4u8), typeslice::u8![4]);
+assert_type_eq_all!(typeslice::push_back!(typeslice::i8![1], -4i8), typeslice::types::I8<1, typeslice::types::I8<-4, typeslice::types::I8Nil>>);
+assert_type_eq_all!(
+    typeslice::push_back!(Hello, '!'),
+    typeslice::from_str!("hello!")
+);
+
+type Api = typeslice::from_str!("/api");
+const_assert!(Api::LIST.starts_with_char('/'));
+const_assert!(!Api::LIST.starts_with_char('a'));
+const_assert!(Api::LIST.ends_with_char('i'));
+const_assert!(!Api::LIST.ends_with_char('/'));
+const_assert!(!Empty::LIST.starts_with_char('/'));
+const_assert!(!Empty::LIST.ends_with_char('/'));
+
+const_assert!(typeslice::len_of!(typeslice::u8![1, 2, 3]) == <typeslice::u8![1, 2, 3]>::LEN);
+const_assert!(typeslice::len_of!(typeslice::i8![-1, -2]) == 2);
+const_assert!(typeslice::len_of!(typeslice::char![]) == 0);
+const_assert!(typeslice::len_of!(typeslice::from_str!("hello")) == Hello::LEN);
+const_assert!(typeslice::len_of!(typeslice::from_str!()) == 0);
+const_assert!(typeslice::len_of!(typeslice::from_bytes!([1, 2])) == 2);
+const_assert!(typeslice::len_of!(typeslice::from_bytes!(b"hello")) == 5);
+const_assert!(typeslice::len_of!(typeslice::utf8!("👋")) == 4);
+
+#[test]
+fn len_of() {
+    let array = [0u8; typeslice::len_of!(typeslice::u8![1, 2, 3])];
+    assert_eq!(array.len(), 3);
+}
+
+const_assert!(matches!(
+    <typeslice::from_str!("1234")>::LIST.parse_u64(),
+    Some(1234)
+));
+const_assert!(matches!(
+    <typeslice::from_str!("18446744073709551615")>::LIST.parse_u64(),
+    Some(u64::MAX)
+));
+const_assert!(<typeslice::from_str!("18446744073709551616")>::LIST
+    .parse_u64()
+    .is_none());
+const_assert!(<typeslice::from_str!("12a")>::LIST.parse_u64().is_none());
+const_assert!(<typeslice::from_str!("-1")>::LIST.parse_u64().is_none());
+const_assert!(Empty::LIST.parse_u64().is_none());
+
+const_assert!(matches!(
+    <typeslice::from_str!("42")>::LIST.parse_usize(),
+    Some(42)
+));
+const_assert!(matches!(
+    <typeslice::from_bytes!(b"1024")>::LIST.parse_usize(),
+    Some(1024)
+));
+const_assert!(matches!(
+    <typeslice::from_bytes!(b"18446744073709551615")>::LIST.parse_u64(),
+    Some(u64::MAX)
+));
+const_assert!(matches!(
+    <typeslice::from_str!("-9223372036854775808")>::LIST.parse_i64(),
+    Some(i64::MIN)
+));
+const_assert!(matches!(
+    <typeslice::from_str!("+9223372036854775807")>::LIST.parse_i64(),
+    Some(i64::MAX)
+));
+const_assert!(<typeslice::from_str!("9223372036854775808")>::LIST
+    .parse_i64()
+    .is_none());
+const_assert!(<typeslice::from_str!("-9223372036854775809")>::LIST
+    .parse_i64()
+    .is_none());
+const_assert!(matches!(
+    <typeslice::from_bytes!(b"-12")>::LIST.parse_isize(),
+    Some(-12)
+));
+const_assert!(<typeslice::from_str!("-")>::LIST.parse_isize().is_none());
+const_assert!(<typeslice::from_str!("+-1")>::LIST.parse_isize().is_none());
+const_assert!(<typeslice::from_bytes!(b"1.0")>::LIST
+    .parse_usize()
+    .is_none());
@@ -0,0 +1,5 @@
+type Overflow = typeslice::from_bytes!([1, 2, 300]);
+type Negative = typeslice::from_bytes!([-1]);
+type Wrong = typeslice::from_bytes!([1u16]);
+
+fn main() {}
@@ -0,0 +1,4 @@
+type Unsuffixed = typeslice::push_back!(typeslice::u8![1, 2, 3], 4);
+type Unsigned = typeslice::push_back!(typeslice::u8![1, 2, 3], -4u8);
+
+fn main() {}
@@ -0,0 +1,7 @@
+type TooMany = <typeslice::u8![1] as typeslice::ops::Take<65>>::Output;
+type SkipTooMany = <typeslice::u8![1] as typeslice::ops::Skip<65>>::Output;
+
+fn main() {
+    let _: Option<TooMany> = None;
+    let _: Option<SkipTooMany> = None;
+}